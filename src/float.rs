@@ -7,7 +7,7 @@ pub struct Float(f64);
 
 /// Constant for floating-point number comparisons
 /// precision is enough for ray-tracer
-const EPSILON: f64 = 1e-5;
+pub const EPSILON: f64 = 1e-5;
 
 impl From<f64> for Float {
     fn from(value: f64) -> Self {
@@ -15,15 +15,16 @@ impl From<f64> for Float {
     }
 }
 
+/// exact equality is checked first so infinities compare equal to themselves
 impl PartialEq<Self> for Float {
     fn eq(&self, other: &Self) -> bool {
-        (self.0 - other.0).abs() < EPSILON
+        self.0 == other.0 || (self.0 - other.0).abs() < EPSILON
     }
 }
 
 impl PartialEq<f64> for Float {
     fn eq(&self, other: &f64) -> bool {
-        (self.0 - other).abs() < EPSILON
+        self.0 == *other || (self.0 - other).abs() < EPSILON
     }
 }
 
@@ -92,6 +93,26 @@ impl Float {
         Float(self.0.powi(exponent))
     }
 
+    pub fn powf(&self, exponent: Float) -> Self {
+        Float(self.0.powf(exponent.0))
+    }
+
+    pub fn abs(&self) -> Self {
+        Float(self.0.abs())
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        Float(self.0.max(other.0))
+    }
+
+    pub fn min(self, other: Self) -> Self {
+        Float(self.0.min(other.0))
+    }
+
+    pub fn floor(&self) -> Self {
+        Float(self.0.floor())
+    }
+
     pub fn sqrt(&self) -> Self {
         Float(self.0.sqrt())
     }
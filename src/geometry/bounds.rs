@@ -0,0 +1,241 @@
+use crate::float::{Float, EPSILON};
+use crate::geometry::{dot_product, point, Matrix, Point, Ray, Vector};
+
+/// Axis-aligned box given by its lowest and highest corners
+///
+/// the default box is empty, its minimum is above its maximum so adding
+/// anything to it replaces both corners
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BoundingBox {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Default for BoundingBox {
+    fn default() -> Self {
+        BoundingBox {
+            min: point(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+            max: point(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+        }
+    }
+}
+
+/// distances at which a ray enters and leaves the slab between `minimum` and `maximum` on one axis
+///
+/// a ray parallel to the slab gets infinite distances so it only hits when it starts inside,
+/// only an exact zero counts as parallel since scaling an object up shrinks ray directions
+/// in its object space and any other direction gives a valid distance
+pub(crate) fn check_axis(
+    origin: Float,
+    direction: Float,
+    minimum: Float,
+    maximum: Float,
+) -> (Float, Float) {
+    let tmin_numerator = minimum - origin;
+    let tmax_numerator = maximum - origin;
+
+    let (tmin, tmax) = if direction.to_number() != 0.0 {
+        (tmin_numerator / direction, tmax_numerator / direction)
+    } else {
+        (
+            tmin_numerator * Float::from(f64::INFINITY),
+            tmax_numerator * Float::from(f64::INFINITY),
+        )
+    };
+
+    if tmin > tmax {
+        (tmax, tmin)
+    } else {
+        (tmin, tmax)
+    }
+}
+
+/// tolerance for treating a quadratic coefficient as zero, relative to the squared length
+/// of `direction` since scaling an object up shrinks ray directions in its object space
+pub(crate) fn parallel_tolerance(direction: Vector) -> Float {
+    dot_product(direction, direction) * Float::from(EPSILON)
+}
+
+/// like `transform * point` but zero entries are skipped, so infinite bounds
+/// stay infinite instead of becoming `0 * inf = NaN`
+fn transform_corner(transform: Matrix<4>, corner: Point) -> Point {
+    let row = |index: usize| {
+        [corner.x, corner.y, corner.z, Float::from(1.0)]
+            .iter()
+            .enumerate()
+            .filter(|(column, _)| transform[(index, *column)].to_number() != 0.0)
+            .fold(Float::from(0.0), |sum, (column, value)| {
+                sum + transform[(index, column)] * *value
+            })
+    };
+    Point {
+        x: row(0),
+        y: row(1),
+        z: row(2),
+    }
+}
+
+impl BoundingBox {
+    pub fn new(min: Point, max: Point) -> Self {
+        BoundingBox { min, max }
+    }
+
+    /// grows the box just enough to contain `point`
+    pub fn add_point(&mut self, point: Point) {
+        self.min = Point {
+            x: self.min.x.min(point.x),
+            y: self.min.y.min(point.y),
+            z: self.min.z.min(point.z),
+        };
+        self.max = Point {
+            x: self.max.x.max(point.x),
+            y: self.max.y.max(point.y),
+            z: self.max.z.max(point.z),
+        };
+    }
+
+    /// smallest box containing both boxes
+    pub fn union(&self, other: &BoundingBox) -> BoundingBox {
+        // the corners of an empty box are infinite, adding them would cover all of space
+        if other.is_empty() {
+            return *self;
+        }
+
+        let mut union = *self;
+        union.add_point(other.min);
+        union.add_point(other.max);
+        union
+    }
+
+    /// true until a point has been added
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    pub fn contains_point(&self, point: Point) -> bool {
+        self.min.x <= point.x
+            && point.x <= self.max.x
+            && self.min.y <= point.y
+            && point.y <= self.max.y
+            && self.min.z <= point.z
+            && point.z <= self.max.z
+    }
+
+    pub fn contains_box(&self, other: &BoundingBox) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    /// box containing all 8 transformed corners,
+    /// it may be larger than the transformed contents e.g. for a rotated sphere
+    ///
+    /// an empty box stays empty, transforming its infinite corners would give an infinite box
+    pub fn transform(&self, transform: Matrix<4>) -> BoundingBox {
+        if self.is_empty() {
+            return *self;
+        }
+
+        let (min, max) = (self.min, self.max);
+        let mut bounds = BoundingBox::default();
+        for x in [min.x, max.x] {
+            for y in [min.y, max.y] {
+                for z in [min.z, max.z] {
+                    bounds.add_point(transform_corner(transform, Point { x, y, z }));
+                }
+            }
+        }
+        bounds
+    }
+
+    /// distances at which a ray enters and leaves the box, using the slab test
+    pub fn distances(&self, ray: &Ray) -> Option<(Float, Float)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let (xtmin, xtmax) = check_axis(ray.origin.x, ray.direction.x, self.min.x, self.max.x);
+        let (ytmin, ytmax) = check_axis(ray.origin.y, ray.direction.y, self.min.y, self.max.y);
+        let (ztmin, ztmax) = check_axis(ray.origin.z, ray.direction.z, self.min.z, self.max.z);
+
+        let tmin = xtmin.max(ytmin).max(ztmin);
+        let tmax = xtmax.min(ytmax).min(ztmax);
+
+        if tmin > tmax {
+            None
+        } else {
+            Some((tmin, tmax))
+        }
+    }
+
+    pub fn intersects(&self, ray: &Ray) -> bool {
+        self.distances(ray).is_some()
+    }
+
+    /// NaN for a box that is infinite along one axis but flat along another
+    pub fn surface_area(&self) -> Float {
+        let (width, height, depth) = (
+            self.max.x - self.min.x,
+            self.max.y - self.min.y,
+            self.max.z - self.min.z,
+        );
+        Float::from(2.0) * (width * height + height * depth + depth * width)
+    }
+
+    pub fn centroid(&self) -> Point {
+        let half = Float::from(0.5);
+        Point {
+            x: (self.min.x + self.max.x) * half,
+            y: (self.min.y + self.max.y) * half,
+            z: (self.min.z + self.max.z) * half,
+        }
+    }
+
+    /// halves of the box on either side of the middle of its longest axis
+    pub fn split(&self) -> (BoundingBox, BoundingBox) {
+        let (width, height, depth) = (
+            self.max.x - self.min.x,
+            self.max.y - self.min.y,
+            self.max.z - self.min.z,
+        );
+        let middle = self.centroid();
+
+        let (left_max, right_min) = if width >= height && width >= depth {
+            (
+                Point {
+                    x: middle.x,
+                    ..self.max
+                },
+                Point {
+                    x: middle.x,
+                    ..self.min
+                },
+            )
+        } else if height >= depth {
+            (
+                Point {
+                    y: middle.y,
+                    ..self.max
+                },
+                Point {
+                    y: middle.y,
+                    ..self.min
+                },
+            )
+        } else {
+            (
+                Point {
+                    z: middle.z,
+                    ..self.max
+                },
+                Point {
+                    z: middle.z,
+                    ..self.min
+                },
+            )
+        };
+
+        (
+            BoundingBox::new(self.min, left_max),
+            BoundingBox::new(right_min, self.max),
+        )
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt;
+
+use crate::float::Float;
+use crate::geometry::{Point, Vector};
+
+/// Square matrix of `N` rows and `N` columns
+/// compares each element using the tolerance of `Float`
+#[derive(Copy, Clone, Debug)]
+pub struct Matrix<const N: usize> {
+    data: [[Float; N]; N],
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MatrixError {
+    /// determinant of the matrix is zero
+    NotInvertible,
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixError::NotInvertible => write!(f, "matrix is not invertible"),
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
+
+impl<const N: usize> Matrix<N> {
+    pub fn new(data: [[Float; N]; N]) -> Self {
+        Matrix { data }
+    }
+
+    pub fn identity() -> Self {
+        let mut data = [[Float::from(0.0); N]; N];
+        for (index, row) in data.iter_mut().enumerate() {
+            row[index] = Float::from(1.0);
+        }
+        Matrix { data }
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut data = [[Float::from(0.0); N]; N];
+        for (row, values) in self.data.iter().enumerate() {
+            for (column, value) in values.iter().enumerate() {
+                data[column][row] = *value;
+            }
+        }
+        Matrix { data }
+    }
+
+    /// copy of the matrix without the given row and column
+    /// `M` must be exactly one less than `N`
+    fn remove_row_and_column<const M: usize>(&self, row: usize, column: usize) -> Matrix<M> {
+        debug_assert_eq!(M + 1, N);
+
+        let mut data = [[Float::from(0.0); M]; M];
+        self.data
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != row)
+            .zip(data.iter_mut())
+            .for_each(|((_, source), target)| {
+                source
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| *index != column)
+                    .zip(target.iter_mut())
+                    .for_each(|((_, value), cell)| *cell = *value);
+            });
+        Matrix { data }
+    }
+}
+
+impl Matrix<2> {
+    pub fn determinant(&self) -> Float {
+        self.data[0][0] * self.data[1][1] - self.data[0][1] * self.data[1][0]
+    }
+}
+
+/// Laplace expansion along the first row, shared by the 3x3 and 4x4 matrices
+macro_rules! impl_cofactor_expansion {
+    ($size:literal, $sub_size:literal) => {
+        impl Matrix<$size> {
+            pub fn submatrix(&self, row: usize, column: usize) -> Matrix<$sub_size> {
+                self.remove_row_and_column(row, column)
+            }
+
+            pub fn minor(&self, row: usize, column: usize) -> Float {
+                self.submatrix(row, column).determinant()
+            }
+
+            pub fn cofactor(&self, row: usize, column: usize) -> Float {
+                let minor = self.minor(row, column);
+                if (row + column) % 2 == 0 {
+                    minor
+                } else {
+                    -minor
+                }
+            }
+
+            pub fn determinant(&self) -> Float {
+                (0..$size).fold(Float::from(0.0), |sum, column| {
+                    sum + self.data[0][column] * self.cofactor(0, column)
+                })
+            }
+        }
+    };
+}
+
+impl_cofactor_expansion!(3, 2);
+impl_cofactor_expansion!(4, 3);
+
+impl Matrix<4> {
+    /// only an exact zero determinant is singular, comparing with `EPSILON` would
+    /// reject small but valid transforms such as `scaling(0.02, 0.02, 0.02)`,
+    /// a NaN or infinite determinant comes from a degenerate matrix and is rejected too
+    pub fn is_invertible(&self) -> bool {
+        let determinant = self.determinant().to_number();
+        determinant != 0.0 && determinant.is_finite()
+    }
+
+    pub fn inverse(&self) -> Result<Self, MatrixError> {
+        let determinant = self.determinant();
+        if !self.is_invertible() {
+            return Err(MatrixError::NotInvertible);
+        }
+
+        let mut data = [[Float::from(0.0); 4]; 4];
+        for (row, values) in data.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                // swapping row and column transposes the cofactor matrix
+                *value = self.cofactor(column, row) / determinant;
+            }
+        }
+        Ok(Matrix { data })
+    }
+}
+
+impl<const N: usize> std::ops::Index<(usize, usize)> for Matrix<N> {
+    type Output = Float;
+
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        &self.data[row][column]
+    }
+}
+
+impl<const N: usize> std::ops::IndexMut<(usize, usize)> for Matrix<N> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        &mut self.data[row][column]
+    }
+}
+
+impl<const N: usize> PartialEq for Matrix<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data
+            .iter()
+            .zip(other.data.iter())
+            .all(|(row, other_row)| row == other_row)
+    }
+}
+
+impl<const N: usize> std::ops::Mul<Self> for Matrix<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut data = [[Float::from(0.0); N]; N];
+        for (row, values) in data.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value = (0..N).fold(Float::from(0.0), |sum, index| {
+                    sum + self.data[row][index] * rhs.data[index][column]
+                });
+            }
+        }
+        Matrix { data }
+    }
+}
+
+impl std::ops::Mul<Point> for Matrix<4> {
+    type Output = Point;
+
+    /// points have an implicit `w` of 1 so they are affected by translation
+    fn mul(self, rhs: Point) -> Self::Output {
+        let row = |index: usize| {
+            let [a, b, c, d] = self.data[index];
+            a * rhs.x + b * rhs.y + c * rhs.z + d
+        };
+        Point {
+            x: row(0),
+            y: row(1),
+            z: row(2),
+        }
+    }
+}
+
+impl std::ops::Mul<Vector> for Matrix<4> {
+    type Output = Vector;
+
+    /// vectors have an implicit `w` of 0 so they ignore translation
+    fn mul(self, rhs: Vector) -> Self::Output {
+        let row = |index: usize| {
+            let [a, b, c, _] = self.data[index];
+            a * rhs.x + b * rhs.y + c * rhs.z
+        };
+        Vector {
+            x: row(0),
+            y: row(1),
+            z: row(2),
+        }
+    }
+}
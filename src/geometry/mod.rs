@@ -1,8 +1,19 @@
 use crate::float::Float;
+pub use crate::geometry::bounds::BoundingBox;
+pub(crate) use crate::geometry::bounds::{check_axis, parallel_tolerance};
+pub use crate::geometry::matrix::{Matrix, MatrixError};
 pub use crate::geometry::point::Point;
+pub use crate::geometry::ray::Ray;
+pub use crate::geometry::transformation::{
+    rotation_x, rotation_y, rotation_z, scaling, shearing, translation, view_transform,
+};
 pub use crate::geometry::vector::Vector;
 
+mod bounds;
+mod matrix;
 mod point;
+mod ray;
+mod transformation;
 mod vector;
 
 pub fn point(x: f64, y: f64, z: f64) -> Point {
@@ -21,6 +32,23 @@ pub fn vector(x: f64, y: f64, z: f64) -> Vector {
     }
 }
 
+pub fn ray(origin: Point, direction: Vector) -> Ray {
+    Ray { origin, direction }
+}
+
+/// box with `min` and `max` as opposite corners
+pub fn bounding_box(min: Point, max: Point) -> BoundingBox {
+    BoundingBox::new(min, max)
+}
+
+pub fn matrix<const N: usize>(rows: [[f64; N]; N]) -> Matrix<N> {
+    Matrix::new(rows.map(|row| row.map(Float::from)))
+}
+
+pub fn identity_matrix() -> Matrix<4> {
+    Matrix::identity()
+}
+
 pub fn magnitude(v: Vector) -> Float {
     (v.x.pow(2) + v.y.pow(2) + v.z.pow(2)).sqrt()
 }
@@ -44,3 +72,8 @@ pub fn cross_product(v1: Vector, v2: Vector) -> Vector {
         z: (v1.x * v2.y) - (v1.y * v2.x),
     }
 }
+
+/// reflect `v` around `normal` as if bouncing off a surface
+pub fn reflect(v: Vector, normal: Vector) -> Vector {
+    v - normal * Float::from(2.0) * dot_product(v, normal)
+}
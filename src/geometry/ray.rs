@@ -0,0 +1,24 @@
+use crate::float::Float;
+use crate::geometry::{Matrix, Point, Vector};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ray {
+    pub origin: Point,
+    pub direction: Vector,
+}
+
+impl Ray {
+    /// point found after travelling `t` units along the ray
+    pub fn position<T: Into<Float>>(&self, t: T) -> Point {
+        self.origin + self.direction * t.into()
+    }
+
+    /// new ray with both origin and direction transformed
+    /// the original ray is left untouched
+    pub fn transform(&self, transform: Matrix<4>) -> Ray {
+        Ray {
+            origin: transform * self.origin,
+            direction: transform * self.direction,
+        }
+    }
+}
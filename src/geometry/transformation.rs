@@ -0,0 +1,142 @@
+use crate::geometry::{cross_product, matrix, normalize, Matrix, Point, Vector};
+
+pub fn translation(x: f64, y: f64, z: f64) -> Matrix<4> {
+    matrix([
+        [1.0, 0.0, 0.0, x],
+        [0.0, 1.0, 0.0, y],
+        [0.0, 0.0, 1.0, z],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+pub fn scaling(x: f64, y: f64, z: f64) -> Matrix<4> {
+    matrix([
+        [x, 0.0, 0.0, 0.0],
+        [0.0, y, 0.0, 0.0],
+        [0.0, 0.0, z, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+/// rotation around the x axis by `radians` (left-handed)
+pub fn rotation_x(radians: f64) -> Matrix<4> {
+    let (sin, cos) = radians.sin_cos();
+    matrix([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, cos, -sin, 0.0],
+        [0.0, sin, cos, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+/// rotation around the y axis by `radians` (left-handed)
+pub fn rotation_y(radians: f64) -> Matrix<4> {
+    let (sin, cos) = radians.sin_cos();
+    matrix([
+        [cos, 0.0, sin, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [-sin, 0.0, cos, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+/// rotation around the z axis by `radians` (left-handed)
+pub fn rotation_z(radians: f64) -> Matrix<4> {
+    let (sin, cos) = radians.sin_cos();
+    matrix([
+        [cos, -sin, 0.0, 0.0],
+        [sin, cos, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+/// moves each component in proportion to the other two components
+/// e.g. `x_y` moves `x` in proportion to `y`
+pub fn shearing(x_y: f64, x_z: f64, y_x: f64, y_z: f64, z_x: f64, z_y: f64) -> Matrix<4> {
+    matrix([
+        [1.0, x_y, x_z, 0.0],
+        [y_x, 1.0, y_z, 0.0],
+        [z_x, z_y, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+/// orients the world relative to an eye at `from` looking towards `to`
+///
+/// `up` only needs to point roughly upwards, it is re-orthogonalized here
+pub fn view_transform(from: Point, to: Point, up: Vector) -> Matrix<4> {
+    let forward = normalize(to - from);
+    let left = cross_product(forward, normalize(up));
+    let true_up = cross_product(left, forward);
+    let orientation = matrix([
+        [
+            left.x.to_number(),
+            left.y.to_number(),
+            left.z.to_number(),
+            0.0,
+        ],
+        [
+            true_up.x.to_number(),
+            true_up.y.to_number(),
+            true_up.z.to_number(),
+            0.0,
+        ],
+        [
+            -forward.x.to_number(),
+            -forward.y.to_number(),
+            -forward.z.to_number(),
+            0.0,
+        ],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+    orientation
+        * translation(
+            -from.x.to_number(),
+            -from.y.to_number(),
+            -from.z.to_number(),
+        )
+}
+
+/// Fluent API for composing transformations
+///
+/// each call applies its transformation *after* the existing ones
+/// so chains read in the order they are applied
+/// ```
+/// use ray_tracer::geometry::{identity_matrix, rotation_x, scaling, translation};
+///
+/// let transform = identity_matrix()
+///     .rotate_x(std::f64::consts::FRAC_PI_2)
+///     .scale(5.0, 5.0, 5.0)
+///     .translate(10.0, 5.0, 7.0);
+///
+/// assert_eq!(
+///     transform,
+///     translation(10.0, 5.0, 7.0) * scaling(5.0, 5.0, 5.0) * rotation_x(std::f64::consts::FRAC_PI_2)
+/// );
+/// ```
+impl Matrix<4> {
+    pub fn translate(self, x: f64, y: f64, z: f64) -> Self {
+        translation(x, y, z) * self
+    }
+
+    pub fn scale(self, x: f64, y: f64, z: f64) -> Self {
+        scaling(x, y, z) * self
+    }
+
+    pub fn rotate_x(self, radians: f64) -> Self {
+        rotation_x(radians) * self
+    }
+
+    pub fn rotate_y(self, radians: f64) -> Self {
+        rotation_y(radians) * self
+    }
+
+    pub fn rotate_z(self, radians: f64) -> Self {
+        rotation_z(radians) * self
+    }
+
+    pub fn shear(self, x_y: f64, x_z: f64, y_x: f64, y_z: f64, z_x: f64, z_y: f64) -> Self {
+        shearing(x_y, x_z, y_x, y_z, z_x, z_y) * self
+    }
+}
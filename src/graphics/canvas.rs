@@ -112,6 +112,33 @@ pub fn canvas_to_ppm(canvas: &Canvas) -> String {
     ppm_file
 }
 
+/// 8-bit RGB PNG image of the canvas
+///
+/// # Panics
+///
+/// if the canvas has no pixels, PNG images can't be empty
+pub fn canvas_to_png(canvas: &Canvas) -> Vec<u8> {
+    let data = canvas
+        .pixels
+        .iter()
+        .flat_map(|color| color.into_array().map(scale_to_srgb))
+        .collect::<Vec<u8>>();
+
+    let mut png_file = vec![];
+    let mut encoder = png::Encoder::new(&mut png_file, canvas.width as u32, canvas.height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| {
+            writer.write_image_data(&data)?;
+            writer.finish()
+        })
+        .unwrap_or_else(|error| panic!("Failed to encode PNG: {}", error));
+
+    png_file
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,58 @@
+use crate::geometry::{dot_product, normalize, reflect, Point, Vector};
+use crate::graphics::{color, Color, Material};
+use crate::shapes::Shape;
+
+/// Light source with no size, radiating equally in every direction
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PointLight {
+    pub position: Point,
+    pub intensity: Color,
+}
+
+/// Phong reflection model
+/// sums the ambient, diffuse and specular contributions of `light` at `point`,
+/// only the ambient contribution remains when the point is `in_shadow`
+///
+/// `object` is needed to map `point` into the space of the material's pattern
+pub fn lighting(
+    material: &Material,
+    object: &dyn Shape,
+    light: &PointLight,
+    point: Point,
+    eyev: Vector,
+    normalv: Vector,
+    in_shadow: bool,
+) -> Color {
+    let surface_color = match &material.pattern {
+        Some(pattern) => pattern.pattern_at_shape(object, point),
+        None => material.color,
+    };
+
+    // combine the surface color with the light's color/intensity
+    let effective_color = surface_color * light.intensity;
+    let lightv = normalize(light.position - point);
+    let ambient = effective_color * material.ambient;
+    if in_shadow {
+        return ambient;
+    }
+
+    // a negative cosine means the light is on the other side of the surface
+    let light_dot_normal = dot_product(lightv, normalv);
+    if light_dot_normal < 0.0 {
+        return ambient;
+    }
+
+    let diffuse = effective_color * material.diffuse * light_dot_normal;
+
+    // a negative cosine means the light reflects away from the eye
+    let reflectv = reflect(-lightv, normalv);
+    let reflect_dot_eye = dot_product(reflectv, eyev);
+    let specular = if reflect_dot_eye <= 0.0 {
+        color(0.0, 0.0, 0.0)
+    } else {
+        let factor = reflect_dot_eye.powf(material.shininess);
+        light.intensity * material.specular * factor
+    };
+
+    ambient + diffuse + specular
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::float::Float;
+use crate::graphics::{color, Color};
+use crate::patterns::Pattern;
+
+/// Surface attributes used by the Phong reflection model
+///
+/// a `pattern` takes precedence over the flat `color`
+#[derive(Clone, Debug)]
+pub struct Material {
+    pub color: Color,
+    pub pattern: Option<Arc<dyn Pattern>>,
+    pub ambient: Float,
+    pub diffuse: Float,
+    pub specular: Float,
+    pub shininess: Float,
+    /// `0` is completely matte and `1` a perfect mirror
+    pub reflective: Float,
+    /// `0` is opaque and `1` lets all light through
+    pub transparency: Float,
+    /// how much light bends entering the material, e.g. `1.0` vacuum, `1.5` glass
+    pub refractive_index: Float,
+}
+
+/// patterns are compared by identity since trait objects cannot be compared by value
+impl PartialEq for Material {
+    fn eq(&self, other: &Self) -> bool {
+        let same_pattern = match (&self.pattern, &other.pattern) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+
+        same_pattern
+            && self.color == other.color
+            && self.ambient == other.ambient
+            && self.diffuse == other.diffuse
+            && self.specular == other.specular
+            && self.shininess == other.shininess
+            && self.reflective == other.reflective
+            && self.transparency == other.transparency
+            && self.refractive_index == other.refractive_index
+    }
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Material {
+            color: color(1.0, 1.0, 1.0),
+            pattern: None,
+            ambient: Float::from(0.1),
+            diffuse: Float::from(0.9),
+            specular: Float::from(0.9),
+            shininess: Float::from(200.0),
+            reflective: Float::from(0.0),
+            transparency: Float::from(0.0),
+            refractive_index: Float::from(1.0),
+        }
+    }
+}
@@ -1,9 +1,14 @@
-pub use crate::graphics::canvas::{canvas_to_ppm, pixel_at, write_pixel, Canvas};
+use crate::geometry::Point;
+pub use crate::graphics::canvas::{canvas_to_png, canvas_to_ppm, pixel_at, write_pixel, Canvas};
 pub use crate::graphics::color::Color;
+pub use crate::graphics::light::{lighting, PointLight};
+pub use crate::graphics::material::Material;
 use crate::Float;
 
 mod canvas;
 mod color;
+mod light;
+mod material;
 
 pub fn color(red: f64, green: f64, blue: f64) -> Color {
     Color {
@@ -22,3 +27,14 @@ pub fn canvas(width: usize, height: usize) -> Canvas {
         pixels: vec![default_color; width * height],
     }
 }
+
+pub fn material() -> Material {
+    Material::default()
+}
+
+pub fn point_light(position: Point, intensity: Color) -> PointLight {
+    PointLight {
+        position,
+        intensity,
+    }
+}
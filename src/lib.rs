@@ -1,5 +1,9 @@
-pub use crate::float::Float;
+pub use crate::float::{Float, EPSILON};
 
 mod float;
 pub mod geometry;
 pub mod graphics;
+pub mod parsers;
+pub mod patterns;
+pub mod scene;
+pub mod shapes;
@@ -1,3 +1,223 @@
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use std::{env, fs, process};
+
+use ray_tracer::graphics::{canvas_to_png, canvas_to_ppm, Canvas};
+use ray_tracer::parsers::parse_scene;
+use ray_tracer::scene::{camera, Camera};
+
+const USAGE: &str = "\
+Usage: ray-tracer render <scene.yml> [options]
+
+Options:
+  -o, --output <file>  image to write, .ppm or .png [default: the scene file with .ppm]
+      --width <n>      width of the image in pixels [default: the camera's]
+      --height <n>     height of the image in pixels [default: the camera's]
+      --samples <n>    rays traced through each pixel [default: 1]
+      --threads <n>    number of render threads [default: one per core]
+  -h, --help           print this message";
+
+/// flags of the `render` command, each one takes a value
+const OPTIONS: [&str; 6] = [
+    "-o",
+    "--output",
+    "--width",
+    "--height",
+    "--samples",
+    "--threads",
+];
+
+/// Settings of the `render` command, unset values come from the scene file
+#[derive(Debug, Default)]
+struct RenderOptions {
+    scene: PathBuf,
+    output: Option<PathBuf>,
+    width: Option<usize>,
+    height: Option<usize>,
+    samples: Option<usize>,
+    threads: Option<usize>,
+}
+
+#[derive(Debug)]
+enum Command {
+    Help,
+    Render(RenderOptions),
+}
+
+/// Image encoder chosen from the extension of the output file
+#[derive(Copy, Clone, Debug)]
+enum Format {
+    Ppm,
+    Png,
+}
+
+fn positive_integer(flag: &str, value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(number) if number > 0 => Ok(number),
+        _ => Err(format!(
+            "`{}` must be a positive integer, got `{}`",
+            flag, value
+        )),
+    }
+}
+
+/// `args` excludes the program name, flags take their value as the next argument or after `=`
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("render") => {}
+        Some("-h" | "--help") => return Ok(Command::Help),
+        Some(command) => return Err(format!("unknown command `{}`", command)),
+        None => return Err(String::from("missing command")),
+    }
+
+    let mut options = RenderOptions::default();
+    let mut scene = None;
+    while let Some(arg) = args.next() {
+        if arg == "-h" || arg == "--help" {
+            return Ok(Command::Help);
+        }
+        if !arg.starts_with('-') {
+            if scene.replace(PathBuf::from(&arg)).is_some() {
+                return Err(format!("unexpected argument `{}`", arg));
+            }
+            continue;
+        }
+
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+            None => (arg, None),
+        };
+        if !OPTIONS.contains(&flag.as_str()) {
+            return Err(format!("unknown option `{}`", flag));
+        }
+        let value = match inline_value.or_else(|| args.next()) {
+            Some(value) => value,
+            None => return Err(format!("`{}` needs a value", flag)),
+        };
+        match flag.as_str() {
+            "-o" | "--output" => options.output = Some(PathBuf::from(value)),
+            "--width" => options.width = Some(positive_integer(&flag, &value)?),
+            "--height" => options.height = Some(positive_integer(&flag, &value)?),
+            "--samples" => options.samples = Some(positive_integer(&flag, &value)?),
+            _ => options.threads = Some(positive_integer(&flag, &value)?),
+        }
+    }
+
+    options.scene = scene.ok_or_else(|| String::from("missing scene file"))?;
+    Ok(Command::Render(options))
+}
+
+fn output_format(path: &Path) -> Result<Format, String> {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase);
+    match extension.as_deref() {
+        Some("ppm") => Ok(Format::Ppm),
+        Some("png") => Ok(Format::Png),
+        _ => Err(format!(
+            "unsupported output format for `{}`, expected a .ppm or .png file",
+            path.display()
+        )),
+    }
+}
+
+fn encode(format: Format, image: &Canvas) -> Vec<u8> {
+    match format {
+        Format::Ppm => canvas_to_ppm(image).into_bytes(),
+        Format::Png => canvas_to_png(image),
+    }
+}
+
+/// camera looking the same way as `scene_camera` with the size asked for on the command line
+///
+/// when only one side is given the other keeps the aspect ratio of the scene's camera
+fn resize_camera(scene_camera: Camera, width: Option<usize>, height: Option<usize>) -> Camera {
+    let (hsize, vsize) = (scene_camera.hsize(), scene_camera.vsize());
+    let scaled = |size: usize, numerator: usize, denominator: usize| {
+        ((size * numerator) as f64 / denominator as f64)
+            .round()
+            .max(1.0) as usize
+    };
+    let (width, height) = match (width, height) {
+        (None, None) => return scene_camera,
+        (Some(width), Some(height)) => (width, height),
+        (Some(width), None) => (width, scaled(width, vsize, hsize)),
+        (None, Some(height)) => (scaled(height, hsize, vsize), height),
+    };
+
+    let mut resized = camera(width, height, scene_camera.field_of_view().to_number());
+    resized.set_transform(scene_camera.transform());
+    resized
+}
+
+fn render(options: RenderOptions) -> Result<(), String> {
+    let output = options
+        .output
+        .unwrap_or_else(|| options.scene.with_extension("ppm"));
+    // fail before rendering rather than after
+    let format = output_format(&output)?;
+
+    let start = Instant::now();
+    let source = fs::read_to_string(&options.scene)
+        .map_err(|error| format!("failed to read `{}`: {}", options.scene.display(), error))?;
+    let scene =
+        parse_scene(&source).map_err(|error| format!("{}: {}", options.scene.display(), error))?;
+    println!(
+        "loaded {} in {:.2?} ({} objects, {} lights)",
+        options.scene.display(),
+        start.elapsed(),
+        scene.world.objects.len(),
+        scene.world.lights.len(),
+    );
+
+    let mut camera = resize_camera(scene.camera, options.width, options.height);
+    camera.set_samples(options.samples.unwrap_or(1));
+    camera.set_threads(options.threads.unwrap_or(0));
+
+    let start = Instant::now();
+    let image = camera.render(&scene.world);
+    let elapsed = start.elapsed();
+
+    let camera_rays = camera.hsize() * camera.vsize() * camera.samples();
+    let rays = scene.world.rays_cast();
+    println!(
+        "rendered {}x{} pixels with {} samples per pixel in {:.2?}",
+        camera.hsize(),
+        camera.vsize(),
+        camera.samples(),
+        elapsed,
+    );
+    println!(
+        "traced {} rays ({} camera rays, {} shadow and secondary rays), {:.0} rays per second",
+        rays,
+        camera_rays,
+        rays - camera_rays,
+        rays as f64 / elapsed.as_secs_f64(),
+    );
+
+    fs::write(&output, encode(format, &image))
+        .map_err(|error| format!("failed to write `{}`: {}", output.display(), error))?;
+    println!("wrote {}", output.display());
+    Ok(())
+}
+
 fn main() {
-    println!("Hello, world!");
+    let result = match parse_args(env::args().skip(1)) {
+        Ok(Command::Help) => {
+            println!("{}", USAGE);
+            return;
+        }
+        Ok(Command::Render(options)) => render(options),
+        Err(error) => {
+            eprintln!("error: {}\n\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(error) = result {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    }
 }
@@ -0,0 +1,21 @@
+pub use crate::parsers::obj::{IgnoredLine, ObjError, ObjFile, ObjTriangle};
+pub use crate::parsers::scene::{Scene, SceneError};
+use crate::shapes::Group;
+
+mod obj;
+mod scene;
+
+/// parses the contents of a Wavefront OBJ file
+pub fn parse_obj_file(source: &str) -> Result<ObjFile, ObjError> {
+    obj::parse_obj(source)
+}
+
+/// group of every triangle in `obj`, with a subgroup for each named group
+pub fn obj_to_group(obj: ObjFile) -> Group {
+    obj.into_group()
+}
+
+/// parses a YAML scene description into the world and the camera looking at it
+pub fn parse_scene(source: &str) -> Result<Scene, SceneError> {
+    scene::parse_scene(source)
+}
@@ -0,0 +1,273 @@
+use std::fmt;
+
+use crate::float::Float;
+use crate::geometry::{point, vector, Point, Vector};
+use crate::shapes::{smooth_triangle, triangle, Group, Shape, SmoothTriangle, Triangle};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ObjError {
+    /// a coordinate of a `v`, `vn` or `vt` statement is not a number
+    InvalidNumber { line: usize, value: String },
+    /// a statement has fewer values than it needs e.g. a vertex with two coordinates
+    MissingValues { line: usize, statement: String },
+    /// a face refers to a vertex, texture vertex or normal that doesn't exist
+    InvalidIndex { line: usize, index: String },
+}
+
+impl fmt::Display for ObjError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ObjError::InvalidNumber { line, value } => {
+                write!(f, "line {}: invalid number `{}`", line, value)
+            }
+            ObjError::MissingValues { line, statement } => {
+                write!(f, "line {}: not enough values for `{}`", line, statement)
+            }
+            ObjError::InvalidIndex { line, index } => {
+                write!(f, "line {}: invalid index `{}`", line, index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ObjError {}
+
+/// Statement the parser skipped because it isn't supported
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IgnoredLine {
+    pub line: usize,
+    pub text: String,
+}
+
+/// Triangle read from a face, smooth when every vertex of the face has a normal
+#[derive(Clone, Debug)]
+pub enum ObjTriangle {
+    Flat(Triangle),
+    Smooth(SmoothTriangle),
+}
+
+impl ObjTriangle {
+    fn into_shape(self) -> Box<dyn Shape> {
+        match self {
+            ObjTriangle::Flat(triangle) => Box::new(triangle),
+            ObjTriangle::Smooth(triangle) => Box::new(triangle),
+        }
+    }
+}
+
+/// Contents of an OBJ file
+///
+/// faces before the first `g` or `o` statement belong to the default group,
+/// vertices and normals are stored in file order but OBJ indices start at 1
+#[derive(Clone, Debug, Default)]
+pub struct ObjFile {
+    pub vertices: Vec<Point>,
+    pub normals: Vec<Vector>,
+    pub texture_vertices: Vec<(Float, Float)>,
+    pub default_group: Vec<ObjTriangle>,
+    pub groups: Vec<(String, Vec<ObjTriangle>)>,
+    pub ignored: Vec<IgnoredLine>,
+}
+
+fn parse_values(
+    line: usize,
+    statement: &str,
+    values: &[&str],
+    count: usize,
+) -> Result<Vec<f64>, ObjError> {
+    if values.len() < count {
+        return Err(ObjError::MissingValues {
+            line,
+            statement: statement.to_string(),
+        });
+    }
+
+    values
+        .iter()
+        .take(count)
+        .map(|value| {
+            value.parse::<f64>().map_err(|_| ObjError::InvalidNumber {
+                line,
+                value: value.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// 0-based position of an OBJ index, negative indices count back from the latest entry
+fn resolve_index(line: usize, index: &str, count: usize) -> Result<usize, ObjError> {
+    let invalid = || ObjError::InvalidIndex {
+        line,
+        index: index.to_string(),
+    };
+
+    let value: i64 = index.parse().map_err(|_| invalid())?;
+    let resolved = if value > 0 {
+        value - 1
+    } else {
+        count as i64 + value
+    };
+
+    if value == 0 || resolved < 0 || resolved >= count as i64 {
+        return Err(invalid());
+    }
+    Ok(resolved as usize)
+}
+
+impl ObjFile {
+    /// 1-based like the indices in the file
+    pub fn vertex(&self, index: usize) -> Option<Point> {
+        index
+            .checked_sub(1)
+            .and_then(|index| self.vertices.get(index).copied())
+    }
+
+    /// 1-based like the indices in the file
+    pub fn normal(&self, index: usize) -> Option<Vector> {
+        index
+            .checked_sub(1)
+            .and_then(|index| self.normals.get(index).copied())
+    }
+
+    pub fn group(&self, name: &str) -> Option<&[ObjTriangle]> {
+        self.groups
+            .iter()
+            .find(|(group, _)| group == name)
+            .map(|(_, triangles)| triangles.as_slice())
+    }
+
+    /// single group holding the default group's triangles and a subgroup per named group
+    ///
+    /// named groups without faces, e.g. an `o` statement directly followed by `g`, are left out
+    pub fn into_group(self) -> Group {
+        let mut result = Group::new();
+        for triangle in self.default_group {
+            result.add_child(triangle.into_shape());
+        }
+        for (_, triangles) in self.groups {
+            if triangles.is_empty() {
+                continue;
+            }
+            let mut subgroup = Group::new();
+            for triangle in triangles {
+                subgroup.add_child(triangle.into_shape());
+            }
+            result.add_child(Box::new(subgroup));
+        }
+        result
+    }
+
+    /// a `v/vt/vn` reference where texture and normal are optional, e.g. `1`, `1/2`, `1//3`
+    fn face_vertex(
+        &self,
+        line: usize,
+        reference: &str,
+    ) -> Result<(Point, Option<Vector>), ObjError> {
+        let mut parts = reference.split('/');
+        let vertex = parts.next().unwrap_or_default();
+        let vertex = self.vertices[resolve_index(line, vertex, self.vertices.len())?];
+
+        if let Some(texture) = parts.next().filter(|texture| !texture.is_empty()) {
+            resolve_index(line, texture, self.texture_vertices.len())?;
+        }
+
+        let normal = match parts.next().filter(|normal| !normal.is_empty()) {
+            Some(normal) => Some(self.normals[resolve_index(line, normal, self.normals.len())?]),
+            None => None,
+        };
+        Ok((vertex, normal))
+    }
+
+    /// polygons are split into a fan of triangles sharing the first vertex
+    fn face(&self, line: usize, references: &[&str]) -> Result<Vec<ObjTriangle>, ObjError> {
+        if references.len() < 3 {
+            return Err(ObjError::MissingValues {
+                line,
+                statement: "f".to_string(),
+            });
+        }
+
+        let vertices = references
+            .iter()
+            .map(|reference| self.face_vertex(line, reference))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (p1, n1) = vertices[0];
+        Ok(vertices[1..]
+            .windows(2)
+            .map(|pair| {
+                let ((p2, n2), (p3, n3)) = (pair[0], pair[1]);
+                match (n1, n2, n3) {
+                    (Some(n1), Some(n2), Some(n3)) => {
+                        ObjTriangle::Smooth(smooth_triangle(p1, p2, p3, n1, n2, n3))
+                    }
+                    _ => ObjTriangle::Flat(triangle(p1, p2, p3)),
+                }
+            })
+            .collect())
+    }
+}
+
+/// reads `v`, `vn`, `vt`, `f`, `g` and `o` statements
+///
+/// blank lines and comments are skipped, any other statement is recorded in `ignored`
+pub fn parse_obj(source: &str) -> Result<ObjFile, ObjError> {
+    let mut obj = ObjFile::default();
+    // index into `obj.groups` of the group faces are added to, `None` for the default group
+    let mut current_group: Option<usize> = None;
+
+    for (index, text) in source.lines().enumerate() {
+        let line = index + 1;
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        let (statement, values) = match tokens.split_first() {
+            Some((statement, _)) if statement.starts_with('#') => continue,
+            Some((statement, values)) => (*statement, values),
+            None => continue,
+        };
+
+        match statement {
+            "v" => {
+                let values = parse_values(line, statement, values, 3)?;
+                obj.vertices.push(point(values[0], values[1], values[2]));
+            }
+            "vn" => {
+                let values = parse_values(line, statement, values, 3)?;
+                obj.normals.push(vector(values[0], values[1], values[2]));
+            }
+            "vt" => {
+                // `v` is optional for 1D textures
+                let values = parse_values(line, statement, values, values.len().clamp(1, 2))?;
+                let v = values.get(1).copied().unwrap_or(0.0);
+                obj.texture_vertices
+                    .push((Float::from(values[0]), Float::from(v)));
+            }
+            "f" => {
+                let triangles = obj.face(line, values)?;
+                match current_group {
+                    Some(group) => obj.groups[group].1.extend(triangles),
+                    None => obj.default_group.extend(triangles),
+                }
+            }
+            "g" | "o" => {
+                let name = values.join(" ");
+                current_group = if name.is_empty() {
+                    None
+                } else {
+                    match obj.groups.iter().position(|(group, _)| *group == name) {
+                        Some(group) => Some(group),
+                        None => {
+                            obj.groups.push((name, vec![]));
+                            Some(obj.groups.len() - 1)
+                        }
+                    }
+                };
+            }
+            _ => obj.ignored.push(IgnoredLine {
+                line,
+                text: text.trim().to_string(),
+            }),
+        }
+    }
+
+    Ok(obj)
+}
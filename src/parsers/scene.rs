@@ -0,0 +1,552 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use yaml_rust::yaml::Hash;
+use yaml_rust::{Yaml, YamlLoader};
+
+use crate::float::Float;
+use crate::geometry::{identity_matrix, point, vector, view_transform, Matrix, Point, Vector};
+use crate::graphics::{color, point_light, Color, Material};
+use crate::patterns::{checkers_pattern, gradient_pattern, ring_pattern, stripe_pattern, Pattern};
+use crate::scene::{camera, Camera, World};
+use crate::shapes::{
+    cone, csg, cube, cylinder, group, plane, sphere, triangle, CsgOperation, Shape,
+};
+
+/// keys every shape accepts on top of its own
+const SHAPE_KEYS: [&str; 3] = ["add", "material", "transform"];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SceneError {
+    /// the file isn't valid YAML or isn't a list of entries
+    InvalidDocument { message: String },
+    /// the entry at `entry` (counting from 1) is malformed,
+    /// `name` is its `add` or `define` statement when it has one
+    InvalidEntry {
+        entry: usize,
+        name: Option<String>,
+        message: String,
+    },
+    /// nothing in the file adds a camera to render the scene with
+    MissingCamera,
+}
+
+impl fmt::Display for SceneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SceneError::InvalidDocument { message } => write!(f, "invalid scene: {}", message),
+            SceneError::InvalidEntry {
+                entry,
+                name: Some(name),
+                message,
+            } => write!(f, "entry {} ({}): {}", entry, name, message),
+            SceneError::InvalidEntry {
+                entry,
+                name: None,
+                message,
+            } => write!(f, "entry {}: {}", entry, message),
+            SceneError::MissingCamera => write!(f, "scene has no camera"),
+        }
+    }
+}
+
+impl std::error::Error for SceneError {}
+
+/// Everything needed to render a scene file
+#[derive(Debug)]
+pub struct Scene {
+    pub world: World,
+    pub camera: Camera,
+}
+
+/// Reads the scene entries in file order
+///
+/// definitions can only refer to definitions above them,
+/// errors are plain messages until `parse_scene` adds the entry they belong to
+#[derive(Default)]
+struct SceneParser {
+    definitions: HashMap<String, Yaml>,
+    world: World,
+    camera: Option<Camera>,
+}
+
+pub(crate) fn parse_scene(source: &str) -> Result<Scene, SceneError> {
+    let documents =
+        YamlLoader::load_from_str(source).map_err(|error| SceneError::InvalidDocument {
+            message: error.to_string(),
+        })?;
+    let entries = match documents.first() {
+        Some(Yaml::Array(entries)) => entries.as_slice(),
+        None => &[],
+        Some(_) => {
+            return Err(SceneError::InvalidDocument {
+                message: String::from("expected a list of entries"),
+            })
+        }
+    };
+
+    let mut parser = SceneParser::default();
+    for (index, entry) in entries.iter().enumerate() {
+        parser
+            .parse_entry(entry)
+            .map_err(|message| SceneError::InvalidEntry {
+                entry: index + 1,
+                name: entry_name(entry),
+                message,
+            })?;
+    }
+
+    Ok(Scene {
+        world: parser.world,
+        camera: parser.camera.ok_or(SceneError::MissingCamera)?,
+    })
+}
+
+/// e.g. `add: sphere` or `define: white-material`
+fn entry_name(entry: &Yaml) -> Option<String> {
+    ["add", "define"].iter().find_map(|statement| {
+        entry[*statement]
+            .as_str()
+            .map(|name| format!("{}: {}", statement, name))
+    })
+}
+
+fn key(name: &str) -> Yaml {
+    Yaml::String(name.to_string())
+}
+
+fn mapping<'a>(value: &'a Yaml, name: &str) -> Result<&'a Hash, String> {
+    value
+        .as_hash()
+        .ok_or_else(|| format!("`{}` must be a mapping", name))
+}
+
+fn required<'a>(hash: &'a Hash, name: &str) -> Result<&'a Yaml, String> {
+    hash.get(&key(name))
+        .ok_or_else(|| format!("missing `{}`", name))
+}
+
+fn check_keys(hash: &Hash, allowed: &[&str]) -> Result<(), String> {
+    for name in hash.keys() {
+        match name.as_str() {
+            Some(name) if allowed.contains(&name) => {}
+            Some(name) => return Err(format!("unknown key `{}`", name)),
+            None => return Err(String::from("keys must be strings")),
+        }
+    }
+    Ok(())
+}
+
+fn check_shape_keys(hash: &Hash, extra: &[&str]) -> Result<(), String> {
+    check_keys(hash, &[&SHAPE_KEYS[..], extra].concat())
+}
+
+fn string<'a>(value: &'a Yaml, name: &str) -> Result<&'a str, String> {
+    value
+        .as_str()
+        .ok_or_else(|| format!("`{}` must be a string", name))
+}
+
+fn number(value: &Yaml, name: &str) -> Result<f64, String> {
+    match value {
+        Yaml::Integer(number) => Ok(*number as f64),
+        Yaml::Real(_) => value
+            .as_f64()
+            .ok_or_else(|| format!("`{}` must be a number", name)),
+        _ => Err(format!("`{}` must be a number", name)),
+    }
+}
+
+fn size(value: &Yaml, name: &str) -> Result<usize, String> {
+    match value.as_i64() {
+        Some(size) if size > 0 => Ok(size as usize),
+        _ => Err(format!("`{}` must be a positive integer", name)),
+    }
+}
+
+fn boolean(value: &Yaml, name: &str) -> Result<bool, String> {
+    value
+        .as_bool()
+        .ok_or_else(|| format!("`{}` must be true or false", name))
+}
+
+fn triple(value: &Yaml, name: &str) -> Result<(f64, f64, f64), String> {
+    match value.as_vec().map(Vec::as_slice) {
+        Some([x, y, z]) => Ok((number(x, name)?, number(y, name)?, number(z, name)?)),
+        _ => Err(format!("`{}` must be a list of three numbers", name)),
+    }
+}
+
+fn point_value(value: &Yaml, name: &str) -> Result<Point, String> {
+    let (x, y, z) = triple(value, name)?;
+    Ok(point(x, y, z))
+}
+
+fn vector_value(value: &Yaml, name: &str) -> Result<Vector, String> {
+    let (x, y, z) = triple(value, name)?;
+    Ok(vector(x, y, z))
+}
+
+fn color_value(value: &Yaml, name: &str) -> Result<Color, String> {
+    let (red, green, blue) = triple(value, name)?;
+    Ok(color(red, green, blue))
+}
+
+/// setting a transform that can't be inverted panics, so reject it up front
+fn invertible(transform: Matrix<4>) -> Result<Matrix<4>, String> {
+    transform
+        .inverse()
+        .map(|_| transform)
+        .map_err(|error| format!("invalid transform: {}", error))
+}
+
+/// mappings are merged with `value` taking precedence, lists are concatenated
+fn extend(base: &Yaml, value: &Yaml) -> Result<Yaml, String> {
+    match (base, value) {
+        (Yaml::Hash(base), Yaml::Hash(value)) => {
+            let mut merged = base.clone();
+            for (name, value) in value {
+                merged.insert(name.clone(), value.clone());
+            }
+            Ok(Yaml::Hash(merged))
+        }
+        (Yaml::Array(base), Yaml::Array(value)) => {
+            Ok(Yaml::Array(base.iter().chain(value).cloned().collect()))
+        }
+        _ => Err(String::from(
+            "`extend` needs a definition of the same kind as `value`",
+        )),
+    }
+}
+
+impl SceneParser {
+    fn parse_entry(&mut self, entry: &Yaml) -> Result<(), String> {
+        let hash = mapping(entry, "entry")?;
+        if hash.contains_key(&key("define")) {
+            return self.define(hash);
+        }
+
+        match string(required(hash, "add")?, "add")? {
+            "camera" => {
+                self.camera = Some(self.camera(hash)?);
+            }
+            "light" => {
+                check_keys(hash, &["add", "at", "intensity"])?;
+                self.world.lights.push(point_light(
+                    point_value(required(hash, "at")?, "at")?,
+                    color_value(required(hash, "intensity")?, "intensity")?,
+                ));
+            }
+            _ => {
+                let shape = self.shape(entry, identity_matrix())?;
+                self.world.objects.push(shape);
+            }
+        }
+        Ok(())
+    }
+
+    fn define(&mut self, hash: &Hash) -> Result<(), String> {
+        check_keys(hash, &["define", "extend", "value"])?;
+        let name = string(required(hash, "define")?, "define")?;
+        let mut value = required(hash, "value")?.clone();
+        if let Some(base) = hash.get(&key("extend")) {
+            value = extend(self.definition(string(base, "extend")?)?, &value)?;
+        }
+        if let Yaml::Array(steps) = &value {
+            value = self.expand_list(name, steps)?;
+        }
+        self.definitions.insert(name.to_string(), value);
+        Ok(())
+    }
+
+    /// replaces the names of earlier definitions in a transform list with their steps,
+    /// so stored lists never refer to other definitions and can't form cycles
+    fn expand_list(&self, name: &str, steps: &[Yaml]) -> Result<Yaml, String> {
+        let mut expanded = vec![];
+        for step in steps {
+            match step {
+                Yaml::String(reference) => match self.definitions.get(reference) {
+                    Some(Yaml::Array(referenced)) => expanded.extend(referenced.iter().cloned()),
+                    Some(_) => return Err(format!("definition `{}` is not a list", reference)),
+                    None if reference == name => {
+                        return Err(format!("definition `{}` refers to itself", name))
+                    }
+                    None => return Err(format!("unknown definition `{}`", reference)),
+                },
+                step => expanded.push(step.clone()),
+            }
+        }
+        Ok(Yaml::Array(expanded))
+    }
+
+    fn definition(&self, name: &str) -> Result<&Yaml, String> {
+        self.definitions
+            .get(name)
+            .ok_or_else(|| format!("unknown definition `{}`", name))
+    }
+
+    fn camera(&self, hash: &Hash) -> Result<Camera, String> {
+        check_keys(
+            hash,
+            &[
+                "add",
+                "width",
+                "height",
+                "field-of-view",
+                "from",
+                "to",
+                "up",
+            ],
+        )?;
+        let mut camera = camera(
+            size(required(hash, "width")?, "width")?,
+            size(required(hash, "height")?, "height")?,
+            number(required(hash, "field-of-view")?, "field-of-view")?,
+        );
+        camera.set_transform(invertible(view_transform(
+            point_value(required(hash, "from")?, "from")?,
+            point_value(required(hash, "to")?, "to")?,
+            vector_value(required(hash, "up")?, "up")?,
+        ))?);
+        Ok(camera)
+    }
+
+    /// `value` is a list of steps applied in order,
+    /// each one either e.g. `[translate, 1, 2, 3]` or the name of a defined list
+    fn transform(&self, value: &Yaml) -> Result<Matrix<4>, String> {
+        let steps = value
+            .as_vec()
+            .ok_or_else(|| String::from("`transform` must be a list"))?;
+        steps.iter().try_fold(identity_matrix(), |transform, step| {
+            self.transform_step(transform, step)
+        })
+    }
+
+    fn transform_step(&self, transform: Matrix<4>, step: &Yaml) -> Result<Matrix<4>, String> {
+        let values = match step {
+            Yaml::String(name) => return Ok(self.transform(self.definition(name)?)? * transform),
+            Yaml::Array(values) => values,
+            _ => {
+                return Err(String::from(
+                    "transform steps must be lists or names of definitions",
+                ))
+            }
+        };
+
+        let (operation, arguments) = values
+            .split_first()
+            .ok_or_else(|| String::from("empty transform step"))?;
+        let operation = string(operation, "transform")?;
+        let arguments = arguments
+            .iter()
+            .map(|argument| number(argument, operation))
+            .collect::<Result<Vec<f64>, String>>()?;
+
+        let count = match operation {
+            "translate" | "scale" => 3,
+            "rotate-x" | "rotate-y" | "rotate-z" => 1,
+            "shear" => 6,
+            _ => return Err(format!("unknown transform `{}`", operation)),
+        };
+        if arguments.len() != count {
+            return Err(format!("`{}` takes {} values", operation, count));
+        }
+
+        let a = &arguments;
+        Ok(match operation {
+            "translate" => transform.translate(a[0], a[1], a[2]),
+            "scale" => transform.scale(a[0], a[1], a[2]),
+            "rotate-x" => transform.rotate_x(a[0]),
+            "rotate-y" => transform.rotate_y(a[0]),
+            "rotate-z" => transform.rotate_z(a[0]),
+            _ => transform.shear(a[0], a[1], a[2], a[3], a[4], a[5]),
+        })
+    }
+
+    fn material(&self, value: &Yaml) -> Result<Material, String> {
+        let value = match value {
+            Yaml::String(name) => self.definition(name)?,
+            value => value,
+        };
+        let hash = mapping(value, "material")?;
+
+        let mut material = Material::default();
+        for (name, value) in hash {
+            let name = string(name, "material key")?;
+            let number = || number(value, name).map(Float::from);
+            match name {
+                "color" => material.color = color_value(value, name)?,
+                "pattern" => material.pattern = Some(self.pattern(value)?),
+                "ambient" => material.ambient = number()?,
+                "diffuse" => material.diffuse = number()?,
+                "specular" => material.specular = number()?,
+                "shininess" => material.shininess = number()?,
+                "reflective" => material.reflective = number()?,
+                "transparency" => material.transparency = number()?,
+                "refractive-index" => material.refractive_index = number()?,
+                _ => return Err(format!("unknown material key `{}`", name)),
+            }
+        }
+        Ok(material)
+    }
+
+    fn pattern(&self, value: &Yaml) -> Result<Arc<dyn Pattern>, String> {
+        let hash = mapping(value, "pattern")?;
+        check_keys(hash, &["type", "colors", "transform"])?;
+
+        let (a, b) = match required(hash, "colors")?.as_vec().map(Vec::as_slice) {
+            Some([a, b]) => (color_value(a, "colors")?, color_value(b, "colors")?),
+            _ => return Err(String::from("`colors` must be a list of two colors")),
+        };
+        let mut pattern: Box<dyn Pattern> = match string(required(hash, "type")?, "type")? {
+            "stripes" => Box::new(stripe_pattern(a, b)),
+            "gradient" => Box::new(gradient_pattern(a, b)),
+            "rings" => Box::new(ring_pattern(a, b)),
+            "checkers" => Box::new(checkers_pattern(a, b)),
+            kind => return Err(format!("unknown pattern `{}`", kind)),
+        };
+        if let Some(transform) = hash.get(&key("transform")) {
+            pattern.set_transform(invertible(self.transform(transform)?)?);
+        }
+        Ok(Arc::from(pattern))
+    }
+
+    /// a shape whose `add` names a definition starts from that definition,
+    /// the keys of `value` override the defined ones
+    fn resolve_shape(&self, value: &Yaml) -> Result<Hash, String> {
+        let mut hash = mapping(value, "shape")?.clone();
+        let mut seen = vec![];
+        while let Some(name) = hash.get(&key("add")).and_then(Yaml::as_str) {
+            let definition = match self.definitions.get(name) {
+                Some(definition @ Yaml::Hash(fields)) if fields.contains_key(&key("add")) => {
+                    definition
+                }
+                _ => break,
+            };
+            if seen.contains(&name.to_string()) {
+                return Err(format!("definition `{}` refers to itself", name));
+            }
+            seen.push(name.to_string());
+
+            hash.remove(&key("add"));
+            hash = match extend(definition, &Yaml::Hash(hash))? {
+                Yaml::Hash(merged) => merged,
+                _ => unreachable!("extending a mapping gives a mapping"),
+            };
+        }
+        Ok(hash)
+    }
+
+    /// `parent_transform` is the combined transform of the groups around the shape,
+    /// a group panics when it can't invert that combined with the shape's own transform
+    /// so both are checked before the shape is built
+    fn shape(&self, value: &Yaml, parent_transform: Matrix<4>) -> Result<Box<dyn Shape>, String> {
+        let hash = self.resolve_shape(value)?;
+        let transform = match hash.get(&key("transform")) {
+            Some(transform) => Some(invertible(self.transform(transform)?)?),
+            None => None,
+        };
+        let world_transform = parent_transform * transform.unwrap_or_else(identity_matrix);
+        invertible(world_transform)
+            .map_err(|error| format!("{} once combined with its groups", error))?;
+
+        let mut shape: Box<dyn Shape> = match string(required(&hash, "add")?, "add")? {
+            "sphere" => {
+                check_shape_keys(&hash, &[])?;
+                Box::new(sphere())
+            }
+            "plane" => {
+                check_shape_keys(&hash, &[])?;
+                Box::new(plane())
+            }
+            "cube" => {
+                check_shape_keys(&hash, &[])?;
+                Box::new(cube())
+            }
+            "cylinder" => {
+                check_shape_keys(&hash, &["min", "max", "closed"])?;
+                let mut cylinder = cylinder();
+                (cylinder.minimum, cylinder.maximum, cylinder.closed) =
+                    extent(&hash, cylinder.minimum, cylinder.maximum, cylinder.closed)?;
+                Box::new(cylinder)
+            }
+            "cone" => {
+                check_shape_keys(&hash, &["min", "max", "closed"])?;
+                let mut cone = cone();
+                (cone.minimum, cone.maximum, cone.closed) =
+                    extent(&hash, cone.minimum, cone.maximum, cone.closed)?;
+                Box::new(cone)
+            }
+            "triangle" => {
+                check_shape_keys(&hash, &["p1", "p2", "p3"])?;
+                Box::new(triangle(
+                    point_value(required(&hash, "p1")?, "p1")?,
+                    point_value(required(&hash, "p2")?, "p2")?,
+                    point_value(required(&hash, "p3")?, "p3")?,
+                ))
+            }
+            "group" => {
+                check_shape_keys(&hash, &["children"])?;
+                let mut group = group();
+                let children = match hash.get(&key("children")) {
+                    Some(children) => children
+                        .as_vec()
+                        .ok_or_else(|| String::from("`children` must be a list"))?
+                        .as_slice(),
+                    None => &[],
+                };
+                for child in children {
+                    group.add_child(self.shape(child, world_transform)?);
+                }
+                Box::new(group)
+            }
+            "csg" => {
+                check_shape_keys(&hash, &["operation", "left", "right"])?;
+                let operation = match string(required(&hash, "operation")?, "operation")? {
+                    "union" => CsgOperation::Union,
+                    "intersection" => CsgOperation::Intersection,
+                    "difference" => CsgOperation::Difference,
+                    operation => return Err(format!("unknown CSG operation `{}`", operation)),
+                };
+                Box::new(csg(
+                    operation,
+                    self.shape(required(&hash, "left")?, world_transform)?,
+                    self.shape(required(&hash, "right")?, world_transform)?,
+                ))
+            }
+            kind => return Err(format!("unknown shape `{}`", kind)),
+        };
+
+        if let Some(material) = hash.get(&key("material")) {
+            shape.set_material(self.material(material)?);
+        }
+        if let Some(transform) = transform {
+            shape.set_transform(transform);
+        }
+        Ok(shape)
+    }
+}
+
+/// `min`, `max` and `closed` of a cylinder or cone, falling back to the given defaults
+fn extent(
+    hash: &Hash,
+    minimum: Float,
+    maximum: Float,
+    closed: bool,
+) -> Result<(Float, Float, bool), String> {
+    Ok((
+        match hash.get(&key("min")) {
+            Some(value) => Float::from(number(value, "min")?),
+            None => minimum,
+        },
+        match hash.get(&key("max")) {
+            Some(value) => Float::from(number(value, "max")?),
+            None => maximum,
+        },
+        match hash.get(&key("closed")) {
+            Some(value) => boolean(value, "closed")?,
+            None => closed,
+        },
+    ))
+}
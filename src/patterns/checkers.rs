@@ -0,0 +1,40 @@
+use crate::geometry::Point;
+use crate::graphics::Color;
+use crate::patterns::{Pattern, PatternProperties};
+
+/// 3D checkerboard of unit cubes alternating between two colors
+#[derive(Clone, Debug)]
+pub struct Checkers {
+    properties: PatternProperties,
+    pub a: Color,
+    pub b: Color,
+}
+
+impl Checkers {
+    pub fn new(a: Color, b: Color) -> Self {
+        Checkers {
+            properties: PatternProperties::default(),
+            a,
+            b,
+        }
+    }
+}
+
+impl Pattern for Checkers {
+    fn properties(&self) -> &PatternProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut PatternProperties {
+        &mut self.properties
+    }
+
+    fn pattern_at(&self, point: Point) -> Color {
+        let sum = point.x.floor() + point.y.floor() + point.z.floor();
+        if (sum.to_number() as i64).rem_euclid(2) == 0 {
+            self.a
+        } else {
+            self.b
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::geometry::Point;
+use crate::graphics::Color;
+use crate::patterns::{Pattern, PatternProperties};
+
+/// Blends linearly from `a` to `b` across each unit along the x axis
+#[derive(Clone, Debug)]
+pub struct Gradient {
+    properties: PatternProperties,
+    pub a: Color,
+    pub b: Color,
+}
+
+impl Gradient {
+    pub fn new(a: Color, b: Color) -> Self {
+        Gradient {
+            properties: PatternProperties::default(),
+            a,
+            b,
+        }
+    }
+}
+
+impl Pattern for Gradient {
+    fn properties(&self) -> &PatternProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut PatternProperties {
+        &mut self.properties
+    }
+
+    fn pattern_at(&self, point: Point) -> Color {
+        let fraction = point.x - point.x.floor();
+        self.a + (self.b - self.a) * fraction
+    }
+}
@@ -0,0 +1,28 @@
+use crate::graphics::Color;
+pub use crate::patterns::checkers::Checkers;
+pub use crate::patterns::gradient::Gradient;
+pub use crate::patterns::pattern::{Pattern, PatternProperties};
+pub use crate::patterns::ring::Ring;
+pub use crate::patterns::stripe::Stripe;
+
+mod checkers;
+mod gradient;
+mod pattern;
+mod ring;
+mod stripe;
+
+pub fn stripe_pattern(a: Color, b: Color) -> Stripe {
+    Stripe::new(a, b)
+}
+
+pub fn gradient_pattern(a: Color, b: Color) -> Gradient {
+    Gradient::new(a, b)
+}
+
+pub fn ring_pattern(a: Color, b: Color) -> Ring {
+    Ring::new(a, b)
+}
+
+pub fn checkers_pattern(a: Color, b: Color) -> Checkers {
+    Checkers::new(a, b)
+}
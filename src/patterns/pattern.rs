@@ -0,0 +1,73 @@
+use std::fmt::Debug;
+
+use crate::geometry::{Matrix, Point};
+use crate::graphics::Color;
+use crate::shapes::Shape;
+
+/// State shared by every pattern
+/// caches the inverse transform since it is needed for every shaded point
+#[derive(Clone, Debug)]
+pub struct PatternProperties {
+    transform: Matrix<4>,
+    inverse_transform: Matrix<4>,
+}
+
+impl PatternProperties {
+    pub fn transform(&self) -> Matrix<4> {
+        self.transform
+    }
+
+    pub fn inverse_transform(&self) -> Matrix<4> {
+        self.inverse_transform
+    }
+
+    /// # Panics
+    ///
+    /// if `transform` is not invertible
+    pub fn set_transform(&mut self, transform: Matrix<4>) {
+        self.inverse_transform = transform
+            .inverse()
+            .unwrap_or_else(|error| panic!("Invalid pattern transform: {}", error));
+        self.transform = transform;
+    }
+}
+
+impl Default for PatternProperties {
+    fn default() -> Self {
+        PatternProperties {
+            transform: Matrix::identity(),
+            inverse_transform: Matrix::identity(),
+        }
+    }
+}
+
+/// Color that varies across the surface of a shape
+///
+/// implementors only work in pattern space through `pattern_at`,
+/// converting world points through the object and pattern transforms is handled here
+pub trait Pattern: Debug + Send + Sync {
+    fn properties(&self) -> &PatternProperties;
+
+    fn properties_mut(&mut self) -> &mut PatternProperties;
+
+    /// color at a point already transformed into pattern space
+    fn pattern_at(&self, point: Point) -> Color;
+
+    fn transform(&self) -> Matrix<4> {
+        self.properties().transform()
+    }
+
+    /// # Panics
+    ///
+    /// if `transform` is not invertible
+    fn set_transform(&mut self, transform: Matrix<4>) {
+        self.properties_mut().set_transform(transform);
+    }
+
+    /// color at `world_point` on `object`, the pattern transform is applied after the object's
+    fn pattern_at_shape(&self, object: &dyn Shape, world_point: Point) -> Color {
+        let object_point = object.world_to_object(world_point);
+        let pattern_point = self.properties().inverse_transform() * object_point;
+        self.pattern_at(pattern_point)
+    }
+}
@@ -0,0 +1,40 @@
+use crate::geometry::Point;
+use crate::graphics::Color;
+use crate::patterns::{Pattern, PatternProperties};
+
+/// Concentric rings around the y axis, alternating every unit of distance in the xz plane
+#[derive(Clone, Debug)]
+pub struct Ring {
+    properties: PatternProperties,
+    pub a: Color,
+    pub b: Color,
+}
+
+impl Ring {
+    pub fn new(a: Color, b: Color) -> Self {
+        Ring {
+            properties: PatternProperties::default(),
+            a,
+            b,
+        }
+    }
+}
+
+impl Pattern for Ring {
+    fn properties(&self) -> &PatternProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut PatternProperties {
+        &mut self.properties
+    }
+
+    fn pattern_at(&self, point: Point) -> Color {
+        let distance = (point.x.pow(2) + point.z.pow(2)).sqrt();
+        if (distance.floor().to_number() as i64).rem_euclid(2) == 0 {
+            self.a
+        } else {
+            self.b
+        }
+    }
+}
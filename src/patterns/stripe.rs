@@ -0,0 +1,39 @@
+use crate::geometry::Point;
+use crate::graphics::Color;
+use crate::patterns::{Pattern, PatternProperties};
+
+/// Alternates between two colors every unit along the x axis
+#[derive(Clone, Debug)]
+pub struct Stripe {
+    properties: PatternProperties,
+    pub a: Color,
+    pub b: Color,
+}
+
+impl Stripe {
+    pub fn new(a: Color, b: Color) -> Self {
+        Stripe {
+            properties: PatternProperties::default(),
+            a,
+            b,
+        }
+    }
+}
+
+impl Pattern for Stripe {
+    fn properties(&self) -> &PatternProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut PatternProperties {
+        &mut self.properties
+    }
+
+    fn pattern_at(&self, point: Point) -> Color {
+        if (point.x.floor().to_number() as i64).rem_euclid(2) == 0 {
+            self.a
+        } else {
+            self.b
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+
+use crate::float::Float;
+use crate::geometry::{normalize, point, ray, Matrix, Ray};
+use crate::graphics::{canvas, color, write_pixel, Canvas, Color};
+use crate::scene::{color_at, World};
+
+/// Maps the canvas onto a view of the world one unit in front of the eye
+///
+/// the size of a pixel in world units is derived from the canvas size and field of view,
+/// so those are only settable through the constructor
+#[derive(Copy, Clone, Debug)]
+pub struct Camera {
+    hsize: usize,
+    vsize: usize,
+    field_of_view: Float,
+    transform: Matrix<4>,
+    inverse_transform: Matrix<4>,
+    half_width: Float,
+    half_height: Float,
+    pixel_size: Float,
+    threads: usize,
+    samples: usize,
+}
+
+/// spreads the samples of a pixel vertically so they don't line up with the horizontal steps
+const GOLDEN_RATIO_CONJUGATE: f64 = 0.618_033_988_749_895;
+
+impl Camera {
+    pub fn new(hsize: usize, vsize: usize, field_of_view: f64) -> Self {
+        let half_view = (field_of_view / 2.0).tan();
+        let aspect = hsize as f64 / vsize as f64;
+        let (half_width, half_height) = if aspect >= 1.0 {
+            (half_view, half_view / aspect)
+        } else {
+            (half_view * aspect, half_view)
+        };
+
+        Camera {
+            hsize,
+            vsize,
+            field_of_view: Float::from(field_of_view),
+            transform: Matrix::identity(),
+            inverse_transform: Matrix::identity(),
+            half_width: Float::from(half_width),
+            half_height: Float::from(half_height),
+            pixel_size: Float::from(half_width * 2.0 / hsize as f64),
+            threads: 0,
+            samples: 1,
+        }
+    }
+
+    pub fn hsize(&self) -> usize {
+        self.hsize
+    }
+
+    pub fn vsize(&self) -> usize {
+        self.vsize
+    }
+
+    pub fn field_of_view(&self) -> Float {
+        self.field_of_view
+    }
+
+    pub fn pixel_size(&self) -> Float {
+        self.pixel_size
+    }
+
+    pub fn transform(&self) -> Matrix<4> {
+        self.transform
+    }
+
+    /// # Panics
+    ///
+    /// if `transform` is not invertible
+    pub fn set_transform(&mut self, transform: Matrix<4>) {
+        self.inverse_transform = transform
+            .inverse()
+            .unwrap_or_else(|error| panic!("Invalid camera transform: {}", error));
+        self.transform = transform;
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// number of threads used by `render`, `0` uses rayon's global pool
+    pub fn set_threads(&mut self, threads: usize) {
+        self.threads = threads;
+    }
+
+    pub fn samples(&self) -> usize {
+        self.samples
+    }
+
+    /// number of rays traced through each pixel by `render`, their colors are averaged
+    ///
+    /// # Panics
+    ///
+    /// if `samples` is `0`
+    pub fn set_samples(&mut self, samples: usize) {
+        assert!(samples > 0, "Camera needs at least one sample per pixel");
+        self.samples = samples;
+    }
+
+    /// ray from the eye through the center of the pixel at (`px`, `py`)
+    pub fn ray_for_pixel(&self, px: usize, py: usize) -> Ray {
+        self.ray_through(px as f64 + 0.5, py as f64 + 0.5)
+    }
+
+    /// ray from the eye through canvas position (`x`, `y`) measured in pixels
+    fn ray_through(&self, x: f64, y: f64) -> Ray {
+        // the camera looks towards -z so +x is to the left
+        let world_x = self.half_width - self.pixel_size * Float::from(x);
+        let world_y = self.half_height - self.pixel_size * Float::from(y);
+
+        let pixel = self.inverse_transform * point(world_x.to_number(), world_y.to_number(), -1.0);
+        let origin = self.inverse_transform * point(0.0, 0.0, 0.0);
+        ray(origin, normalize(pixel - origin))
+    }
+
+    /// average color of `samples` rays spread evenly across the pixel at (`px`, `py`)
+    ///
+    /// the sample positions are fixed so a single sample goes through the center
+    /// and repeated renders give the same image
+    fn color_for_pixel(&self, world: &World, px: usize, py: usize) -> Color {
+        let samples = self.samples as f64;
+        let total = (0..self.samples).fold(color(0.0, 0.0, 0.0), |total, sample| {
+            let dx = (sample as f64 + 0.5) / samples;
+            let dy = (sample as f64 * GOLDEN_RATIO_CONJUGATE + 0.5).fract();
+            let ray = self.ray_through(px as f64 + dx, py as f64 + dy);
+            total + color_at(world, &ray, world.max_depth)
+        });
+        total * Float::from(1.0 / samples)
+    }
+
+    /// renders scanlines in parallel
+    ///
+    /// each pixel only depends on its own ray so the image is identical for any thread count
+    pub fn render(&self, world: &World) -> Canvas {
+        let render_scanlines = || {
+            (0..self.vsize)
+                .into_par_iter()
+                .map(|y| {
+                    (0..self.hsize)
+                        .map(|x| self.color_for_pixel(world, x, y))
+                        .collect::<Vec<Color>>()
+                })
+                .collect::<Vec<Vec<Color>>>()
+        };
+
+        let scanlines = match self.threads {
+            0 => render_scanlines(),
+            threads => ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap_or_else(|error| panic!("Failed to build render thread pool: {}", error))
+                .install(render_scanlines),
+        };
+
+        let mut image = canvas(self.hsize, self.vsize);
+        for (y, scanline) in scanlines.into_iter().enumerate() {
+            for (x, color) in scanline.into_iter().enumerate() {
+                write_pixel(&mut image, x, y, color);
+            }
+        }
+        image
+    }
+}
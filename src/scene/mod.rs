@@ -0,0 +1,41 @@
+use crate::geometry::{point, scaling};
+use crate::graphics::{color, point_light, Material};
+pub use crate::scene::camera::Camera;
+pub use crate::scene::world::{
+    color_at, intersect_world, is_shadowed, prepare_computations, reflected_color, refracted_color,
+    schlick, shade_hit, Computations, World,
+};
+use crate::shapes::{sphere, Shape};
+use crate::Float;
+
+mod camera;
+mod world;
+
+/// camera rendering an `hsize` x `vsize` canvas with a `field_of_view` in radians
+pub fn camera(hsize: usize, vsize: usize, field_of_view: f64) -> Camera {
+    Camera::new(hsize, vsize, field_of_view)
+}
+
+pub fn world() -> World {
+    World::default()
+}
+
+/// two concentric spheres lit from the upper left, used throughout the tests
+pub fn default_world() -> World {
+    let mut outer = sphere();
+    outer.set_material(Material {
+        color: color(0.8, 1.0, 0.6),
+        diffuse: Float::from(0.7),
+        specular: Float::from(0.2),
+        ..Material::default()
+    });
+
+    let mut inner = sphere();
+    inner.set_transform(scaling(0.5, 0.5, 0.5));
+
+    World {
+        objects: vec![Box::new(outer), Box::new(inner)],
+        lights: vec![point_light(point(-10.0, 10.0, -10.0), color(1.0, 1.0, 1.0))],
+        ..World::default()
+    }
+}
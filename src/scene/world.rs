@@ -0,0 +1,244 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::float::{Float, EPSILON};
+use crate::geometry::{dot_product, magnitude, normalize, ray, reflect, Point, Ray, Vector};
+use crate::graphics::{color, lighting, Color, PointLight};
+use crate::shapes::{Intersection, Intersections, Shape};
+
+/// Collection of every object and light source in a scene
+#[derive(Debug)]
+pub struct World {
+    pub objects: Vec<Box<dyn Shape>>,
+    pub lights: Vec<PointLight>,
+    /// how many times a ray may bounce, stops mirrors facing each other from recursing forever
+    pub max_depth: usize,
+    /// counted by `intersect_world`, shared by every render thread
+    pub(crate) rays: AtomicUsize,
+}
+
+impl World {
+    /// number of rays intersected with the world so far, including shadow and secondary rays
+    pub fn rays_cast(&self) -> usize {
+        self.rays.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for World {
+    fn default() -> Self {
+        World {
+            objects: vec![],
+            lights: vec![],
+            max_depth: 5,
+            rays: AtomicUsize::new(0),
+        }
+    }
+}
+
+/// State of an intersection needed for shading, computed once per hit
+#[derive(Copy, Clone, Debug)]
+pub struct Computations<'a> {
+    pub t: Float,
+    pub object: &'a dyn Shape,
+    pub point: Point,
+    /// `point` nudged along the normal to escape self-intersection
+    pub over_point: Point,
+    /// `point` nudged below the surface where refracted rays originate
+    pub under_point: Point,
+    pub eyev: Vector,
+    pub normalv: Vector,
+    pub reflectv: Vector,
+    /// whether the ray originated inside the object
+    pub inside: bool,
+    /// refractive index of the material being exited
+    pub n1: Float,
+    /// refractive index of the material being entered
+    pub n2: Float,
+}
+
+/// intersections of `ray` with every object in the world, sorted by `t`
+pub fn intersect_world<'a>(world: &'a World, ray: &Ray) -> Intersections<'a> {
+    world.rays.fetch_add(1, Ordering::Relaxed);
+    world
+        .objects
+        .iter()
+        .flat_map(|object| object.intersect(ray))
+        .collect()
+}
+
+/// `xs` are all the intersections along `ray`, needed to know which objects contain the hit
+pub fn prepare_computations<'a>(
+    hit: &Intersection<'a>,
+    ray: &Ray,
+    xs: &Intersections<'a>,
+) -> Computations<'a> {
+    let point = ray.position(hit.t);
+    let eyev = -ray.direction;
+    let mut normalv = hit.object.normal_at_hit(point, hit);
+
+    // flip the normal so it always faces the eye
+    let inside = dot_product(normalv, eyev) < 0.0;
+    if inside {
+        normalv = -normalv;
+    }
+
+    let (n1, n2) = refractive_indices(hit, xs);
+
+    Computations {
+        t: hit.t,
+        object: hit.object,
+        point,
+        over_point: point + normalv * Float::from(EPSILON),
+        under_point: point - normalv * Float::from(EPSILON),
+        eyev,
+        normalv,
+        reflectv: reflect(ray.direction, normalv),
+        inside,
+        n1,
+        n2,
+    }
+}
+
+/// refractive indices on either side of `hit`
+///
+/// walks the intersections in order tracking which objects the ray is currently inside,
+/// the most recently entered object is the one whose material surrounds the ray
+fn refractive_indices(hit: &Intersection, xs: &Intersections) -> (Float, Float) {
+    let refractive_index = |containers: &[&dyn Shape]| match containers.last() {
+        Some(object) => object.material().refractive_index,
+        None => Float::from(1.0),
+    };
+
+    let mut containers: Vec<&dyn Shape> = vec![];
+    let mut n1 = Float::from(1.0);
+    for i in xs {
+        if i == hit {
+            n1 = refractive_index(&containers);
+        }
+
+        match containers
+            .iter()
+            .position(|object| std::ptr::addr_eq(*object, i.object))
+        {
+            Some(index) => {
+                containers.remove(index);
+            }
+            None => containers.push(i.object),
+        }
+
+        if i == hit {
+            return (n1, refractive_index(&containers));
+        }
+    }
+
+    (n1, refractive_index(&containers))
+}
+
+/// whether any object lies between `point` and `light`
+pub fn is_shadowed(world: &World, point: Point, light: &PointLight) -> bool {
+    let v = light.position - point;
+    let distance = magnitude(v);
+    let shadow_ray = ray(point, normalize(v));
+
+    match intersect_world(world, &shadow_ray).hit() {
+        Some(hit) => hit.t < distance,
+        None => false,
+    }
+}
+
+/// color at the intersection described by `comps`, summed over every light
+///
+/// shadows are tested from `over_point` so the surface does not shadow itself,
+/// `remaining` limits how many more reflected rays may be traced
+pub fn shade_hit(world: &World, comps: &Computations, remaining: usize) -> Color {
+    let surface = world
+        .lights
+        .iter()
+        .fold(color(0.0, 0.0, 0.0), |result, light| {
+            result
+                + lighting(
+                    comps.object.material(),
+                    comps.object,
+                    light,
+                    comps.over_point,
+                    comps.eyev,
+                    comps.normalv,
+                    is_shadowed(world, comps.over_point, light),
+                )
+        });
+
+    let reflected = reflected_color(world, comps, remaining);
+    let refracted = refracted_color(world, comps, remaining);
+
+    // surfaces that both reflect and refract split the light with the Fresnel effect
+    let material = comps.object.material();
+    if material.reflective > 0.0 && material.transparency > 0.0 {
+        let reflectance = schlick(comps);
+        return surface + reflected * reflectance + refracted * (Float::from(1.0) - reflectance);
+    }
+
+    surface + reflected + refracted
+}
+
+/// color seen in the reflection at `comps`, scaled by how reflective the surface is
+pub fn reflected_color(world: &World, comps: &Computations, remaining: usize) -> Color {
+    let reflective = comps.object.material().reflective;
+    if remaining == 0 || reflective == 0.0 {
+        return color(0.0, 0.0, 0.0);
+    }
+
+    let reflect_ray = ray(comps.over_point, comps.reflectv);
+    color_at(world, &reflect_ray, remaining - 1) * reflective
+}
+
+/// color seen through a transparent surface, bending the ray with Snell's law
+pub fn refracted_color(world: &World, comps: &Computations, remaining: usize) -> Color {
+    let transparency = comps.object.material().transparency;
+    if remaining == 0 || transparency == 0.0 {
+        return color(0.0, 0.0, 0.0);
+    }
+
+    let n_ratio = comps.n1 / comps.n2;
+    let cos_i = dot_product(comps.eyev, comps.normalv);
+    let sin2_t = n_ratio.pow(2) * (Float::from(1.0) - cos_i.pow(2));
+
+    // total internal reflection, no light is refracted
+    if sin2_t > 1.0 {
+        return color(0.0, 0.0, 0.0);
+    }
+
+    let cos_t = (Float::from(1.0) - sin2_t).sqrt();
+    let direction = comps.normalv * (n_ratio * cos_i - cos_t) - comps.eyev * n_ratio;
+    let refract_ray = ray(comps.under_point, direction);
+
+    color_at(world, &refract_ray, remaining - 1) * transparency
+}
+
+/// Schlick's approximation of the Fresnel effect
+/// the fraction of light reflected rather than refracted at the surface
+pub fn schlick(comps: &Computations) -> Float {
+    let mut cos = dot_product(comps.eyev, comps.normalv);
+
+    if comps.n1 > comps.n2 {
+        let n = comps.n1 / comps.n2;
+        let sin2_t = n.pow(2) * (Float::from(1.0) - cos.pow(2));
+        if sin2_t > 1.0 {
+            return Float::from(1.0);
+        }
+        // use cos(theta_t) when leaving the denser material
+        cos = (Float::from(1.0) - sin2_t).sqrt();
+    }
+
+    let r0 = ((comps.n1 - comps.n2) / (comps.n1 + comps.n2)).pow(2);
+    r0 + (Float::from(1.0) - r0) * (Float::from(1.0) - cos).pow(5)
+}
+
+/// color seen along `ray`, black when nothing is hit
+///
+/// start with `world.max_depth` as the `remaining` recursion depth
+pub fn color_at(world: &World, ray: &Ray, remaining: usize) -> Color {
+    let xs = intersect_world(world, ray);
+    match xs.hit() {
+        Some(hit) => shade_hit(world, &prepare_computations(&hit, ray, &xs), remaining),
+        None => color(0.0, 0.0, 0.0),
+    }
+}
@@ -0,0 +1,143 @@
+use crate::float::Float;
+use crate::geometry::{BoundingBox, Point};
+
+/// cost of testing a ray against one more bounding box,
+/// relative to intersecting a ray with one child
+const TRAVERSAL_COST: f64 = 1.0;
+
+/// Where a child ends up when its group is divided
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum Side {
+    Stay,
+    Left,
+    Right,
+}
+
+fn component(point: Point, axis: usize) -> Float {
+    match axis {
+        0 => point.x,
+        1 => point.y,
+        _ => point.z,
+    }
+}
+
+/// best split of the children into two groups according to the surface area heuristic
+///
+/// children are sorted by centroid along each axis and every split between two distinct
+/// centroids is costed as the chance of a ray entering each side times its child count,
+/// `None` when no split is cheaper than testing every child
+fn surface_area_split(bounds: &[BoundingBox], bounded: &[usize]) -> Option<Vec<Side>> {
+    if bounded.len() < 2 {
+        return None;
+    }
+
+    let total = bounded
+        .iter()
+        .fold(BoundingBox::default(), |union, &index| {
+            union.union(&bounds[index])
+        });
+    let leaf_cost = bounded.len() as f64;
+
+    let mut best: Option<(f64, Vec<usize>, usize)> = None;
+    for axis in 0..3 {
+        let mut sorted = bounded.to_vec();
+        sorted.sort_by(|&a, &b| {
+            let (a, b) = (
+                component(bounds[a].centroid(), axis),
+                component(bounds[b].centroid(), axis),
+            );
+            a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        // right_areas[i] is the area of everything from i onwards
+        let mut right_areas = vec![0.0; sorted.len()];
+        let mut right = BoundingBox::default();
+        for (i, &index) in sorted.iter().enumerate().rev() {
+            right = right.union(&bounds[index]);
+            right_areas[i] = right.surface_area().to_number();
+        }
+
+        let mut left = BoundingBox::default();
+        for i in 0..sorted.len() - 1 {
+            left = left.union(&bounds[sorted[i]]);
+            let (current, next) = (
+                component(bounds[sorted[i]].centroid(), axis),
+                component(bounds[sorted[i + 1]].centroid(), axis),
+            );
+            if current == next {
+                continue;
+            }
+
+            let left_count = (i + 1) as f64;
+            let right_count = (sorted.len() - i - 1) as f64;
+            let cost = TRAVERSAL_COST
+                + (left.surface_area().to_number() * left_count + right_areas[i + 1] * right_count)
+                    / total.surface_area().to_number();
+            if best
+                .as_ref()
+                .is_none_or(|(best_cost, _, _)| cost < *best_cost)
+            {
+                best = Some((cost, sorted.clone(), i + 1));
+            }
+        }
+    }
+
+    let (cost, sorted, split) = best?;
+    if cost >= leaf_cost {
+        return None;
+    }
+
+    let mut sides = vec![Side::Stay; bounds.len()];
+    for (position, &index) in sorted.iter().enumerate() {
+        sides[index] = if position < split {
+            Side::Left
+        } else {
+            Side::Right
+        };
+    }
+    Some(sides)
+}
+
+/// halves the bounds of the children along their longest axis,
+/// only children that fit entirely inside one half are moved
+fn midpoint_split(bounds: &[BoundingBox], bounded: &[usize]) -> Option<Vec<Side>> {
+    let total = bounded
+        .iter()
+        .fold(BoundingBox::default(), |union, &index| {
+            union.union(&bounds[index])
+        });
+    let (left, right) = total.split();
+
+    let mut sides = vec![Side::Stay; bounds.len()];
+    for &index in bounded {
+        if left.contains_box(&bounds[index]) {
+            sides[index] = Side::Left;
+        } else if right.contains_box(&bounds[index]) {
+            sides[index] = Side::Right;
+        }
+    }
+
+    // moving every child into one subgroup would only add a level without splitting anything
+    let count = |side| sides.iter().filter(|&&s| s == side).count();
+    let (left_count, right_count) = (count(Side::Left), count(Side::Right));
+    if left_count + right_count == 0 || left_count == bounds.len() || right_count == bounds.len() {
+        return None;
+    }
+    Some(sides)
+}
+
+/// decides which children of a group move into a left or right subgroup
+///
+/// unbounded children such as planes would make every box infinite so they always stay,
+/// the midpoint split is used when the surface area heuristic finds nothing worth splitting
+pub(crate) fn partition(bounds: &[BoundingBox]) -> Option<Vec<Side>> {
+    let bounded: Vec<usize> = (0..bounds.len())
+        .filter(|&index| bounds[index].surface_area().to_number().is_finite())
+        .collect();
+
+    if bounded.is_empty() {
+        return None;
+    }
+
+    surface_area_split(bounds, &bounded).or_else(|| midpoint_split(bounds, &bounded))
+}
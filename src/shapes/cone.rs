@@ -0,0 +1,144 @@
+use crate::float::{Float, EPSILON};
+use crate::geometry::{
+    bounding_box, magnitude, parallel_tolerance, point, vector, BoundingBox, Point, Ray, Vector,
+};
+use crate::shapes::cylinder::check_cap;
+use crate::shapes::{intersection, Intersection, Intersections, Shape, ShapeProperties};
+
+/// Double-napped cone around the y axis of its object space, with its tip at the origin
+///
+/// the radius at any height is the absolute value of `y`,
+/// truncation and caps behave like those of a `Cylinder`
+#[derive(Clone, Debug)]
+pub struct Cone {
+    properties: ShapeProperties,
+    pub minimum: Float,
+    pub maximum: Float,
+    pub closed: bool,
+}
+
+impl Cone {
+    pub fn new() -> Self {
+        Cone::default()
+    }
+
+    fn intersect_caps<'a>(&'a self, ray: &Ray, xs: &mut Vec<Intersection<'a>>) {
+        // caps can only be hit by a ray that moves along y
+        if !self.closed || ray.direction.y.abs() < magnitude(ray.direction) * Float::from(EPSILON) {
+            return;
+        }
+
+        for y in [self.minimum, self.maximum] {
+            let t = (y - ray.origin.y) / ray.direction.y;
+            if check_cap(ray, t, y.abs()) {
+                xs.push(intersection(t, self));
+            }
+        }
+    }
+}
+
+impl Default for Cone {
+    fn default() -> Self {
+        Cone {
+            properties: ShapeProperties::default(),
+            minimum: Float::from(f64::NEG_INFINITY),
+            maximum: Float::from(f64::INFINITY),
+            closed: false,
+        }
+    }
+}
+
+/// tolerance for treating `b` as zero, relative to the lengths of the ray's origin and
+/// direction since `b` is linear in each of them
+///
+/// the tolerance is zero for a ray starting at the tip, but so is `b`
+fn b_tolerance(ray: &Ray) -> Float {
+    magnitude(ray.origin - point(0.0, 0.0, 0.0)) * magnitude(ray.direction) * Float::from(EPSILON)
+}
+
+impl Shape for Cone {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let (origin, direction) = (ray.origin, ray.direction);
+        let a = direction.x.pow(2) - direction.y.pow(2) + direction.z.pow(2);
+        let b = Float::from(2.0)
+            * (origin.x * direction.x - origin.y * direction.y + origin.z * direction.z);
+        let c = origin.x.pow(2) - origin.y.pow(2) + origin.z.pow(2);
+
+        let mut xs = vec![];
+        let walls = if a.abs() >= parallel_tolerance(direction) {
+            let discriminant = b.pow(2) - Float::from(4.0) * a * c;
+            if discriminant < 0.0 {
+                return Intersections::default();
+            }
+
+            let two_a = Float::from(2.0) * a;
+            vec![
+                (-b - discriminant.sqrt()) / two_a,
+                (-b + discriminant.sqrt()) / two_a,
+            ]
+        } else if b.abs() > b_tolerance(ray) {
+            // a ray parallel to one of the nappes only hits the other one
+            vec![-c / (Float::from(2.0) * b)]
+        } else {
+            vec![]
+        };
+
+        for t in walls {
+            let y = origin.y + t * direction.y;
+            if self.minimum < y && y < self.maximum {
+                xs.push(intersection(t, self));
+            }
+        }
+
+        self.intersect_caps(ray, &mut xs);
+        Intersections::new(xs)
+    }
+
+    /// the radius is widest at whichever end is furthest from the tip
+    fn bounds(&self) -> BoundingBox {
+        let limit = self.minimum.abs().max(self.maximum.abs());
+        bounding_box(
+            Point {
+                x: -limit,
+                y: self.minimum,
+                z: -limit,
+            },
+            Point {
+                x: limit,
+                y: self.maximum,
+                z: limit,
+            },
+        )
+    }
+
+    fn local_normal_at(&self, object_point: Point) -> Vector {
+        let distance = object_point.x.pow(2) + object_point.z.pow(2);
+
+        if distance < self.maximum.pow(2) && object_point.y >= self.maximum - Float::from(EPSILON) {
+            vector(0.0, 1.0, 0.0)
+        } else if distance < self.minimum.pow(2)
+            && object_point.y <= self.minimum + Float::from(EPSILON)
+        {
+            vector(0.0, -1.0, 0.0)
+        } else {
+            // the wall slopes away from the tip on both nappes
+            let mut y = distance.sqrt();
+            if object_point.y > 0.0 {
+                y = -y;
+            }
+            vector(
+                object_point.x.to_number(),
+                y.to_number(),
+                object_point.z.to_number(),
+            )
+        }
+    }
+}
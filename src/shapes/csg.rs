@@ -0,0 +1,153 @@
+use crate::geometry::{BoundingBox, Matrix, Point, Ray, Vector};
+use crate::shapes::{Intersections, Shape, ShapeProperties};
+
+/// How the two shapes of a `Csg` are combined
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CsgOperation {
+    /// everything inside either shape
+    Union,
+    /// only what is inside both shapes
+    Intersection,
+    /// the left shape with the right shape carved out of it
+    Difference,
+}
+
+/// whether an intersection is on the surface of the combined shape
+///
+/// `left_hit` is true when the left shape was hit, `inside_left` and `inside_right` tell
+/// whether the hit is inside the left and right shapes
+pub fn intersection_allowed(
+    operation: CsgOperation,
+    left_hit: bool,
+    inside_left: bool,
+    inside_right: bool,
+) -> bool {
+    match operation {
+        CsgOperation::Union => (left_hit && !inside_right) || (!left_hit && !inside_left),
+        CsgOperation::Intersection => (left_hit && inside_right) || (!left_hit && inside_left),
+        CsgOperation::Difference => (left_hit && !inside_right) || (!left_hit && inside_left),
+    }
+}
+
+/// Constructive solid geometry, two shapes combined by an `operation`
+///
+/// the children are positioned relative to the CSG shape just like the children of a group
+#[derive(Debug)]
+pub struct Csg {
+    properties: ShapeProperties,
+    pub operation: CsgOperation,
+    children: [Box<dyn Shape>; 2],
+    bounds: BoundingBox,
+}
+
+impl Csg {
+    pub fn new(
+        operation: CsgOperation,
+        mut left: Box<dyn Shape>,
+        mut right: Box<dyn Shape>,
+    ) -> Self {
+        // filtering needs every intersection of the children, not just those up to their hit
+        left.keep_all_intersections();
+        right.keep_all_intersections();
+
+        Csg {
+            properties: ShapeProperties::default(),
+            operation,
+            bounds: left
+                .parent_space_bounds()
+                .union(&right.parent_space_bounds()),
+            children: [left, right],
+        }
+    }
+
+    pub fn left(&self) -> &dyn Shape {
+        self.children[0].as_ref()
+    }
+
+    pub fn right(&self) -> &dyn Shape {
+        self.children[1].as_ref()
+    }
+
+    /// intersections on the surface of the combined shape,
+    /// walking `xs` in order tracks whether each one is inside the other shape
+    pub fn filter_intersections<'a>(&self, xs: Intersections<'a>) -> Intersections<'a> {
+        let (mut inside_left, mut inside_right) = (false, false);
+        let mut result = vec![];
+
+        for i in xs {
+            let left_hit = self.left().includes(i.object);
+            if intersection_allowed(self.operation, left_hit, inside_left, inside_right) {
+                result.push(i);
+            }
+
+            if left_hit {
+                inside_left = !inside_left;
+            } else {
+                inside_right = !inside_right;
+            }
+        }
+        Intersections::new(result)
+    }
+
+    fn update_children(&mut self) {
+        let world_transform = self.properties.world_transform();
+        for child in &mut self.children {
+            child.set_parent_transform(world_transform);
+        }
+    }
+}
+
+impl Shape for Csg {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn children(&self) -> &[Box<dyn Shape>] {
+        &self.children
+    }
+
+    fn includes(&self, other: &dyn Shape) -> bool {
+        std::ptr::addr_eq(self, other) || self.children.iter().any(|child| child.includes(other))
+    }
+
+    fn set_transform(&mut self, transform: Matrix<4>) {
+        self.properties.set_transform(transform);
+        self.update_children();
+    }
+
+    fn set_parent_transform(&mut self, parent_transform: Matrix<4>) {
+        self.properties.set_parent_transform(parent_transform);
+        self.update_children();
+    }
+
+    fn divide(&mut self, threshold: usize) {
+        for child in &mut self.children {
+            child.divide(threshold);
+        }
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        if !self.bounds.intersects(ray) {
+            return Intersections::default();
+        }
+
+        let mut xs = self.left().intersect(ray);
+        xs.extend(self.right().intersect(ray));
+        self.filter_intersections(xs)
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        self.bounds
+    }
+
+    /// # Panics
+    ///
+    /// always, rays only ever hit the children of a CSG shape so their normals are used instead
+    fn local_normal_at(&self, _object_point: Point) -> Vector {
+        panic!("CSG shapes have no surface, normals must be computed on their children")
+    }
+}
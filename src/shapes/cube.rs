@@ -0,0 +1,64 @@
+use crate::float::Float;
+use crate::geometry::{bounding_box, check_axis, point, vector, BoundingBox, Point, Ray, Vector};
+use crate::shapes::{intersection, Intersections, Shape, ShapeProperties};
+
+/// Axis-aligned cube spanning -1 to 1 on every axis of its object space
+#[derive(Clone, Debug, Default)]
+pub struct Cube {
+    properties: ShapeProperties,
+}
+
+impl Cube {
+    pub fn new() -> Self {
+        Cube::default()
+    }
+}
+
+impl Shape for Cube {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    /// slab method, the ray hits when the largest entry distance is before the smallest exit
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let (minimum, maximum) = (Float::from(-1.0), Float::from(1.0));
+        let (xtmin, xtmax) = check_axis(ray.origin.x, ray.direction.x, minimum, maximum);
+        let (ytmin, ytmax) = check_axis(ray.origin.y, ray.direction.y, minimum, maximum);
+        let (ztmin, ztmax) = check_axis(ray.origin.z, ray.direction.z, minimum, maximum);
+
+        let tmin = xtmin.max(ytmin).max(ztmin);
+        let tmax = xtmax.min(ytmax).min(ztmax);
+
+        if tmin > tmax {
+            return Intersections::default();
+        }
+
+        Intersections::new(vec![intersection(tmin, self), intersection(tmax, self)])
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        bounding_box(point(-1.0, -1.0, -1.0), point(1.0, 1.0, 1.0))
+    }
+
+    /// the normal points out of the face whose axis has the largest component,
+    /// edges and corners pick the first of the tied axes
+    fn local_normal_at(&self, object_point: Point) -> Vector {
+        let (x, y, z) = (
+            object_point.x.abs(),
+            object_point.y.abs(),
+            object_point.z.abs(),
+        );
+
+        if x >= y && x >= z {
+            vector(object_point.x.to_number(), 0.0, 0.0)
+        } else if y >= z {
+            vector(0.0, object_point.y.to_number(), 0.0)
+        } else {
+            vector(0.0, 0.0, object_point.z.to_number())
+        }
+    }
+}
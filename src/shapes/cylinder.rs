@@ -0,0 +1,123 @@
+use crate::float::{Float, EPSILON};
+use crate::geometry::{
+    bounding_box, magnitude, parallel_tolerance, vector, BoundingBox, Point, Ray, Vector,
+};
+use crate::shapes::{intersection, Intersection, Intersections, Shape, ShapeProperties};
+
+/// Cylinder of radius 1 around the y axis of its object space
+///
+/// infinitely long unless truncated by `minimum` and `maximum` (both exclusive),
+/// `closed` adds caps to the truncated ends
+#[derive(Clone, Debug)]
+pub struct Cylinder {
+    properties: ShapeProperties,
+    pub minimum: Float,
+    pub maximum: Float,
+    pub closed: bool,
+}
+
+impl Cylinder {
+    pub fn new() -> Self {
+        Cylinder::default()
+    }
+
+    fn intersect_caps<'a>(&'a self, ray: &Ray, xs: &mut Vec<Intersection<'a>>) {
+        // caps can only be hit by a ray that moves along y
+        if !self.closed || ray.direction.y.abs() < magnitude(ray.direction) * Float::from(EPSILON) {
+            return;
+        }
+
+        for y in [self.minimum, self.maximum] {
+            let t = (y - ray.origin.y) / ray.direction.y;
+            if check_cap(ray, t, Float::from(1.0)) {
+                xs.push(intersection(t, self));
+            }
+        }
+    }
+}
+
+impl Default for Cylinder {
+    fn default() -> Self {
+        Cylinder {
+            properties: ShapeProperties::default(),
+            minimum: Float::from(f64::NEG_INFINITY),
+            maximum: Float::from(f64::INFINITY),
+            closed: false,
+        }
+    }
+}
+
+/// whether the ray at `t` lies within `radius` of the y axis, i.e. on a cap
+pub(crate) fn check_cap(ray: &Ray, t: Float, radius: Float) -> bool {
+    let x = ray.origin.x + t * ray.direction.x;
+    let z = ray.origin.z + t * ray.direction.z;
+    x.pow(2) + z.pow(2) <= radius.pow(2) + Float::from(EPSILON)
+}
+
+impl Shape for Cylinder {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let mut xs = vec![];
+
+        // a ray parallel to the y axis can only hit the caps
+        let a = ray.direction.x.pow(2) + ray.direction.z.pow(2);
+        if a.abs() >= parallel_tolerance(ray.direction) {
+            let b = Float::from(2.0)
+                * (ray.origin.x * ray.direction.x + ray.origin.z * ray.direction.z);
+            let c = ray.origin.x.pow(2) + ray.origin.z.pow(2) - Float::from(1.0);
+
+            let discriminant = b.pow(2) - Float::from(4.0) * a * c;
+            if discriminant < 0.0 {
+                return Intersections::default();
+            }
+
+            let two_a = Float::from(2.0) * a;
+            for t in [
+                (-b - discriminant.sqrt()) / two_a,
+                (-b + discriminant.sqrt()) / two_a,
+            ] {
+                let y = ray.origin.y + t * ray.direction.y;
+                if self.minimum < y && y < self.maximum {
+                    xs.push(intersection(t, self));
+                }
+            }
+        }
+
+        self.intersect_caps(ray, &mut xs);
+        Intersections::new(xs)
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        bounding_box(
+            Point {
+                x: Float::from(-1.0),
+                y: self.minimum,
+                z: Float::from(-1.0),
+            },
+            Point {
+                x: Float::from(1.0),
+                y: self.maximum,
+                z: Float::from(1.0),
+            },
+        )
+    }
+
+    fn local_normal_at(&self, object_point: Point) -> Vector {
+        let distance = object_point.x.pow(2) + object_point.z.pow(2);
+
+        if distance < 1.0 && object_point.y >= self.maximum - Float::from(EPSILON) {
+            vector(0.0, 1.0, 0.0)
+        } else if distance < 1.0 && object_point.y <= self.minimum + Float::from(EPSILON) {
+            vector(0.0, -1.0, 0.0)
+        } else {
+            vector(object_point.x.to_number(), 0.0, object_point.z.to_number())
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use crate::float::Float;
+use crate::geometry::{BoundingBox, Matrix, Point, Ray, Vector};
+use crate::shapes::bvh::{partition, Side};
+use crate::shapes::{Intersections, Shape, ShapeProperties};
+
+/// Collection of shapes transformed together as a single unit
+///
+/// children are positioned relative to the group, so a child's transform is applied first
+/// and the group's transform after it
+///
+/// children can't be changed once added, so their bounds in group space are cached
+/// to skip any child whose box a ray misses, `divide` turns a large group into a
+/// bounding volume hierarchy of nested groups
+#[derive(Debug, Default)]
+pub struct Group {
+    properties: ShapeProperties,
+    children: Vec<Box<dyn Shape>>,
+    child_bounds: Vec<BoundingBox>,
+    bounds: BoundingBox,
+    keep_all_intersections: bool,
+}
+
+impl Group {
+    pub fn new() -> Self {
+        Group::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    pub fn add_child(&mut self, mut child: Box<dyn Shape>) {
+        child.set_parent_transform(self.properties.world_transform());
+        let child_bounds = child.parent_space_bounds();
+        self.bounds = self.bounds.union(&child_bounds);
+        self.child_bounds.push(child_bounds);
+        self.children.push(child);
+    }
+
+    /// wraps `children` in a new group added to this one
+    fn add_subgroup(&mut self, children: Vec<Box<dyn Shape>>) {
+        let mut subgroup = Group::new();
+        if self.keep_all_intersections {
+            subgroup.keep_all_intersections();
+        }
+        for child in children {
+            subgroup.add_child(child);
+        }
+        self.add_child(Box::new(subgroup));
+    }
+
+    fn update_children(&mut self) {
+        let world_transform = self.properties.world_transform();
+        for child in &mut self.children {
+            child.set_parent_transform(world_transform);
+        }
+    }
+}
+
+impl Shape for Group {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn children(&self) -> &[Box<dyn Shape>] {
+        &self.children
+    }
+
+    fn includes(&self, other: &dyn Shape) -> bool {
+        std::ptr::addr_eq(self, other) || self.children.iter().any(|child| child.includes(other))
+    }
+
+    fn keep_all_intersections(&mut self) {
+        self.keep_all_intersections = true;
+        for child in &mut self.children {
+            child.keep_all_intersections();
+        }
+    }
+
+    fn set_transform(&mut self, transform: Matrix<4>) {
+        self.properties.set_transform(transform);
+        self.update_children();
+    }
+
+    fn set_parent_transform(&mut self, parent_transform: Matrix<4>) {
+        self.properties.set_parent_transform(parent_transform);
+        self.update_children();
+    }
+
+    /// the ray is already in group space which is each child's parent space
+    ///
+    /// children are visited in the order the ray enters their boxes and the search stops once
+    /// a box starts beyond the nearest hit, so intersections past the hit may be left out
+    /// unless `keep_all_intersections` was called
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        if !self.bounds.intersects(ray) {
+            return Intersections::default();
+        }
+
+        let mut candidates: Vec<(Float, &dyn Shape)> = self
+            .children
+            .iter()
+            .zip(&self.child_bounds)
+            .filter_map(|(child, bounds)| {
+                bounds
+                    .distances(ray)
+                    .map(|(tmin, _)| (tmin, child.as_ref()))
+            })
+            .collect();
+        candidates.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut xs = Intersections::default();
+        let mut nearest: Option<Float> = None;
+        for (entry, child) in candidates {
+            if !self.keep_all_intersections && nearest.is_some_and(|t| entry > t) {
+                break;
+            }
+
+            let child_xs = child.intersect(ray);
+            if let Some(hit) = child_xs.hit() {
+                nearest = Some(nearest.map_or(hit.t, |t| t.min(hit.t)));
+            }
+            xs.extend(child_xs);
+        }
+        xs
+    }
+
+    /// groups with at least `threshold` children are split in two, then every child is divided
+    fn divide(&mut self, threshold: usize) {
+        if self.children.len() >= threshold {
+            if let Some(sides) = partition(&self.child_bounds) {
+                let children = std::mem::take(&mut self.children);
+                self.child_bounds.clear();
+                self.bounds = BoundingBox::default();
+
+                let (mut left, mut right) = (vec![], vec![]);
+                for (child, side) in children.into_iter().zip(sides) {
+                    match side {
+                        Side::Stay => self.add_child(child),
+                        Side::Left => left.push(child),
+                        Side::Right => right.push(child),
+                    }
+                }
+                for subgroup in [left, right] {
+                    if !subgroup.is_empty() {
+                        self.add_subgroup(subgroup);
+                    }
+                }
+            }
+        }
+
+        for child in &mut self.children {
+            child.divide(threshold);
+        }
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        self.bounds
+    }
+
+    /// # Panics
+    ///
+    /// always, rays only ever hit the children of a group so their normals are used instead
+    fn local_normal_at(&self, _object_point: Point) -> Vector {
+        panic!("Groups have no surface, normals must be computed on their children")
+    }
+}
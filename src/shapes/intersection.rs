@@ -0,0 +1,94 @@
+use std::cmp::Ordering;
+
+use crate::float::Float;
+use crate::shapes::Shape;
+
+/// Distance `t` along a ray where it crosses the surface of `object`
+///
+/// `u` and `v` locate the hit on a triangle relative to its corners and are 0 for other shapes
+#[derive(Copy, Clone, Debug)]
+pub struct Intersection<'a> {
+    pub t: Float,
+    pub object: &'a dyn Shape,
+    pub u: Float,
+    pub v: Float,
+}
+
+impl PartialEq for Intersection<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.t == other.t && std::ptr::addr_eq(self.object, other.object)
+    }
+}
+
+/// Collection of intersections kept sorted by ascending `t`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Intersections<'a> {
+    list: Vec<Intersection<'a>>,
+}
+
+fn compare_distance(a: &Intersection, b: &Intersection) -> Ordering {
+    a.t.partial_cmp(&b.t).unwrap_or(Ordering::Equal)
+}
+
+impl<'a> Intersections<'a> {
+    pub fn new(mut list: Vec<Intersection<'a>>) -> Self {
+        list.sort_by(compare_distance);
+        Intersections { list }
+    }
+
+    /// visible intersection i.e. the lowest non-negative `t`
+    /// intersections behind the ray origin are never a hit
+    pub fn hit(&self) -> Option<Intersection<'a>> {
+        self.list.iter().find(|i| i.t >= 0.0).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Intersection<'a>> {
+        self.list.iter()
+    }
+
+    /// merge another set of intersections while preserving the ordering
+    pub fn extend(&mut self, other: Intersections<'a>) {
+        self.list.extend(other.list);
+        self.list.sort_by(compare_distance);
+    }
+}
+
+impl<'a> std::ops::Index<usize> for Intersections<'a> {
+    type Output = Intersection<'a>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.list[index]
+    }
+}
+
+impl<'a> FromIterator<Intersection<'a>> for Intersections<'a> {
+    fn from_iter<I: IntoIterator<Item = Intersection<'a>>>(iter: I) -> Self {
+        Intersections::new(iter.into_iter().collect())
+    }
+}
+
+impl<'a> IntoIterator for Intersections<'a> {
+    type Item = Intersection<'a>;
+    type IntoIter = std::vec::IntoIter<Intersection<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b Intersections<'a> {
+    type Item = &'b Intersection<'a>;
+    type IntoIter = std::slice::Iter<'b, Intersection<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter()
+    }
+}
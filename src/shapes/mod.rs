@@ -0,0 +1,110 @@
+use crate::float::Float;
+use crate::geometry::{Point, Vector};
+use crate::graphics::Material;
+pub use crate::shapes::cone::Cone;
+pub use crate::shapes::csg::{intersection_allowed, Csg, CsgOperation};
+pub use crate::shapes::cube::Cube;
+pub use crate::shapes::cylinder::Cylinder;
+pub use crate::shapes::group::Group;
+pub use crate::shapes::intersection::{Intersection, Intersections};
+pub use crate::shapes::plane::Plane;
+pub use crate::shapes::shape::{Shape, ShapeProperties};
+pub use crate::shapes::smooth_triangle::SmoothTriangle;
+pub use crate::shapes::sphere::Sphere;
+pub use crate::shapes::triangle::Triangle;
+
+mod bvh;
+mod cone;
+mod csg;
+mod cube;
+mod cylinder;
+mod group;
+mod intersection;
+mod plane;
+mod shape;
+mod smooth_triangle;
+mod sphere;
+mod triangle;
+
+pub fn sphere() -> Sphere {
+    Sphere::new()
+}
+
+/// sphere with a fully transparent glass material
+pub fn glass_sphere() -> Sphere {
+    let mut sphere = Sphere::new();
+    sphere.set_material(Material {
+        transparency: Float::from(1.0),
+        refractive_index: Float::from(1.5),
+        ..Material::default()
+    });
+    sphere
+}
+
+pub fn plane() -> Plane {
+    Plane::new()
+}
+
+pub fn cube() -> Cube {
+    Cube::new()
+}
+
+pub fn cylinder() -> Cylinder {
+    Cylinder::new()
+}
+
+pub fn cone() -> Cone {
+    Cone::new()
+}
+
+pub fn triangle(p1: Point, p2: Point, p3: Point) -> Triangle {
+    Triangle::new(p1, p2, p3)
+}
+
+/// triangle with a normal at each corner, the normals are interpolated across its surface
+pub fn smooth_triangle(
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    n1: Vector,
+    n2: Vector,
+    n3: Vector,
+) -> SmoothTriangle {
+    SmoothTriangle::new(p1, p2, p3, n1, n2, n3)
+}
+
+pub fn group() -> Group {
+    Group::new()
+}
+
+/// combines `left` and `right` according to `operation`
+pub fn csg(operation: CsgOperation, left: Box<dyn Shape>, right: Box<dyn Shape>) -> Csg {
+    Csg::new(operation, left, right)
+}
+
+pub fn intersection<T: Into<Float>>(t: T, object: &dyn Shape) -> Intersection<'_> {
+    Intersection {
+        t: t.into(),
+        object,
+        u: Float::from(0.0),
+        v: Float::from(0.0),
+    }
+}
+
+pub fn intersection_with_uv<T: Into<Float>>(
+    t: T,
+    object: &dyn Shape,
+    u: T,
+    v: T,
+) -> Intersection<'_> {
+    Intersection {
+        t: t.into(),
+        object,
+        u: u.into(),
+        v: v.into(),
+    }
+}
+
+pub fn intersections(list: Vec<Intersection<'_>>) -> Intersections<'_> {
+    Intersections::new(list)
+}
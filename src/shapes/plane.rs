@@ -0,0 +1,51 @@
+use crate::float::{Float, EPSILON};
+use crate::geometry::{bounding_box, magnitude, point, vector, BoundingBox, Point, Ray, Vector};
+use crate::shapes::{intersection, Intersections, Shape, ShapeProperties};
+
+/// Infinite xz plane through the origin of its object space
+#[derive(Clone, Debug, Default)]
+pub struct Plane {
+    properties: ShapeProperties,
+}
+
+impl Plane {
+    pub fn new() -> Self {
+        Plane::default()
+    }
+}
+
+impl Shape for Plane {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    /// a ray parallel to the plane never hits it and a coplanar ray is treated as a miss,
+    /// since the plane has no thickness
+    ///
+    /// parallel is relative to the length of the direction, scaling the plane up shrinks
+    /// ray directions in its object space
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        if ray.direction.y.abs() < magnitude(ray.direction) * Float::from(EPSILON) {
+            return Intersections::default();
+        }
+
+        let t = -ray.origin.y / ray.direction.y;
+        Intersections::new(vec![intersection(t, self)])
+    }
+
+    /// infinite in x and z but flat in y
+    fn bounds(&self) -> BoundingBox {
+        bounding_box(
+            point(f64::NEG_INFINITY, 0.0, f64::NEG_INFINITY),
+            point(f64::INFINITY, 0.0, f64::INFINITY),
+        )
+    }
+
+    fn local_normal_at(&self, _object_point: Point) -> Vector {
+        vector(0.0, 1.0, 0.0)
+    }
+}
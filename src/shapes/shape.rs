@@ -0,0 +1,177 @@
+use std::fmt::Debug;
+
+use crate::geometry::{normalize, BoundingBox, Matrix, Point, Ray, Vector};
+use crate::graphics::Material;
+use crate::shapes::{Intersection, Intersections};
+
+/// State shared by every shape
+/// caches the inverse transforms since they are needed for every ray
+///
+/// `parent_transform` is the combined transform of every group containing the shape,
+/// groups keep it up to date so converting from world space never walks the hierarchy
+#[derive(Clone, Debug)]
+pub struct ShapeProperties {
+    transform: Matrix<4>,
+    inverse_transform: Matrix<4>,
+    parent_transform: Matrix<4>,
+    world_inverse_transform: Matrix<4>,
+    pub material: Material,
+}
+
+impl ShapeProperties {
+    pub fn transform(&self) -> Matrix<4> {
+        self.transform
+    }
+
+    pub fn inverse_transform(&self) -> Matrix<4> {
+        self.inverse_transform
+    }
+
+    /// # Panics
+    ///
+    /// if `transform` is not invertible
+    pub fn set_transform(&mut self, transform: Matrix<4>) {
+        self.inverse_transform = transform
+            .inverse()
+            .unwrap_or_else(|error| panic!("Invalid shape transform: {}", error));
+        self.transform = transform;
+        self.update_world_inverse_transform();
+    }
+
+    pub fn parent_transform(&self) -> Matrix<4> {
+        self.parent_transform
+    }
+
+    pub fn set_parent_transform(&mut self, parent_transform: Matrix<4>) {
+        self.parent_transform = parent_transform;
+        self.update_world_inverse_transform();
+    }
+
+    /// transform from object space all the way to world space
+    pub fn world_transform(&self) -> Matrix<4> {
+        self.parent_transform * self.transform
+    }
+
+    /// transform from world space all the way to object space
+    pub fn world_inverse_transform(&self) -> Matrix<4> {
+        self.world_inverse_transform
+    }
+
+    fn update_world_inverse_transform(&mut self) {
+        self.world_inverse_transform = self
+            .world_transform()
+            .inverse()
+            .unwrap_or_else(|error| panic!("Invalid shape transform: {}", error));
+    }
+}
+
+impl Default for ShapeProperties {
+    fn default() -> Self {
+        ShapeProperties {
+            transform: Matrix::identity(),
+            inverse_transform: Matrix::identity(),
+            parent_transform: Matrix::identity(),
+            world_inverse_transform: Matrix::identity(),
+            material: Material::default(),
+        }
+    }
+}
+
+/// Common intersection and normal pipeline for all primitives
+///
+/// implementors only work in object space through `local_intersect` and `local_normal_at`,
+/// converting rays and normals between world and object space is handled here
+pub trait Shape: Debug + Send + Sync {
+    fn properties(&self) -> &ShapeProperties;
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties;
+
+    /// intersections with a ray already transformed into object space
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_>;
+
+    /// normal at a point already transformed into object space
+    fn local_normal_at(&self, point: Point) -> Vector;
+
+    /// normal at an object space point using where the ray hit the surface,
+    /// only needed by shapes whose normal depends on more than the point
+    fn local_normal_at_hit(&self, point: Point, _hit: &Intersection) -> Vector {
+        self.local_normal_at(point)
+    }
+
+    /// box containing the shape in its object space
+    fn bounds(&self) -> BoundingBox;
+
+    /// box containing the shape in the space of its parent group
+    fn parent_space_bounds(&self) -> BoundingBox {
+        self.bounds().transform(self.transform())
+    }
+
+    fn transform(&self) -> Matrix<4> {
+        self.properties().transform()
+    }
+
+    /// # Panics
+    ///
+    /// if `transform` is not invertible
+    fn set_transform(&mut self, transform: Matrix<4>) {
+        self.properties_mut().set_transform(transform);
+    }
+
+    fn material(&self) -> &Material {
+        &self.properties().material
+    }
+
+    fn set_material(&mut self, material: Material) {
+        self.properties_mut().material = material;
+    }
+
+    fn intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let local_ray = ray.transform(self.properties().inverse_transform());
+        self.local_intersect(&local_ray)
+    }
+
+    /// shapes contained by this one, only groups have any
+    fn children(&self) -> &[Box<dyn Shape>] {
+        &[]
+    }
+
+    /// whether `other` is this shape or one of the shapes it contains
+    fn includes(&self, other: &dyn Shape) -> bool {
+        std::ptr::addr_eq(self as *const Self, other as *const dyn Shape)
+    }
+
+    /// called by CSG shapes on their children, so groups stop leaving out
+    /// the intersections beyond their nearest hit
+    fn keep_all_intersections(&mut self) {}
+
+    /// splits groups with at least `threshold` children into a bounding volume hierarchy,
+    /// nothing else has children to split
+    fn divide(&mut self, _threshold: usize) {}
+
+    /// called by a group on its children whenever the transforms above them change
+    fn set_parent_transform(&mut self, parent_transform: Matrix<4>) {
+        self.properties_mut().set_parent_transform(parent_transform);
+    }
+
+    /// converts a world space point to object space, through every containing group
+    fn world_to_object(&self, world_point: Point) -> Point {
+        self.properties().world_inverse_transform() * world_point
+    }
+
+    /// converts an object space normal to world space, through every containing group
+    fn normal_to_world(&self, object_normal: Vector) -> Vector {
+        // the inverse transpose keeps normals perpendicular under non-uniform scaling
+        normalize(self.properties().world_inverse_transform().transpose() * object_normal)
+    }
+
+    fn normal_at(&self, world_point: Point) -> Vector {
+        let local_normal = self.local_normal_at(self.world_to_object(world_point));
+        self.normal_to_world(local_normal)
+    }
+
+    /// like `normal_at` but with the intersection that found `world_point`
+    fn normal_at_hit(&self, world_point: Point, hit: &Intersection) -> Vector {
+        let local_normal = self.local_normal_at_hit(self.world_to_object(world_point), hit);
+        self.normal_to_world(local_normal)
+    }
+}
@@ -0,0 +1,108 @@
+use crate::float::Float;
+use crate::geometry::{dot_product, BoundingBox, Point, Ray, Vector};
+use crate::shapes::triangle::{intersect_triangle, triangle_bounds};
+use crate::shapes::{intersection_with_uv, Intersection, Intersections, Shape, ShapeProperties};
+
+/// Triangle with a normal at each corner, used to make meshes of flat triangles look curved
+///
+/// the normal at a hit blends the corner normals by how close the hit is to each corner
+#[derive(Clone, Debug)]
+pub struct SmoothTriangle {
+    properties: ShapeProperties,
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    n1: Vector,
+    n2: Vector,
+    n3: Vector,
+    e1: Vector,
+    e2: Vector,
+}
+
+impl SmoothTriangle {
+    pub fn new(p1: Point, p2: Point, p3: Point, n1: Vector, n2: Vector, n3: Vector) -> Self {
+        SmoothTriangle {
+            properties: ShapeProperties::default(),
+            p1,
+            p2,
+            p3,
+            n1,
+            n2,
+            n3,
+            e1: p2 - p1,
+            e2: p3 - p1,
+        }
+    }
+
+    pub fn p1(&self) -> Point {
+        self.p1
+    }
+
+    pub fn p2(&self) -> Point {
+        self.p2
+    }
+
+    pub fn p3(&self) -> Point {
+        self.p3
+    }
+
+    pub fn n1(&self) -> Vector {
+        self.n1
+    }
+
+    pub fn n2(&self) -> Vector {
+        self.n2
+    }
+
+    pub fn n3(&self) -> Vector {
+        self.n3
+    }
+
+    /// `u` weighs `n2` and `v` weighs `n3`, `n1` gets whatever is left
+    fn interpolate_normal(&self, u: Float, v: Float) -> Vector {
+        self.n2 * u + self.n3 * v + self.n1 * (Float::from(1.0) - u - v)
+    }
+}
+
+impl Shape for SmoothTriangle {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        match intersect_triangle(ray, self.p1, self.e1, self.e2) {
+            Some((t, u, v)) => Intersections::new(vec![intersection_with_uv(t, self, u, v)]),
+            None => Intersections::default(),
+        }
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        triangle_bounds(self.p1, self.p2, self.p3)
+    }
+
+    /// recovers `u` and `v` from the point when there is no intersection to read them from
+    fn local_normal_at(&self, object_point: Point) -> Vector {
+        let p1_to_point = object_point - self.p1;
+        let (d00, d01, d11) = (
+            dot_product(self.e1, self.e1),
+            dot_product(self.e1, self.e2),
+            dot_product(self.e2, self.e2),
+        );
+        let (d20, d21) = (
+            dot_product(p1_to_point, self.e1),
+            dot_product(p1_to_point, self.e2),
+        );
+        let denominator = d00 * d11 - d01 * d01;
+        let u = (d11 * d20 - d01 * d21) / denominator;
+        let v = (d00 * d21 - d01 * d20) / denominator;
+        self.interpolate_normal(u, v)
+    }
+
+    fn local_normal_at_hit(&self, _object_point: Point, hit: &Intersection) -> Vector {
+        self.interpolate_normal(hit.u, hit.v)
+    }
+}
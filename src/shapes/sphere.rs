@@ -0,0 +1,53 @@
+use crate::float::Float;
+use crate::geometry::{bounding_box, dot_product, point, BoundingBox, Point, Ray, Vector};
+use crate::shapes::{intersection, Intersections, Shape, ShapeProperties};
+
+/// Unit sphere centred on the origin of its object space
+#[derive(Clone, Debug, Default)]
+pub struct Sphere {
+    properties: ShapeProperties,
+}
+
+impl Sphere {
+    pub fn new() -> Self {
+        Sphere::default()
+    }
+}
+
+impl Shape for Sphere {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    /// a tangent ray returns the same distance twice and a miss returns nothing
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        let sphere_to_ray = ray.origin - point(0.0, 0.0, 0.0);
+
+        let a = dot_product(ray.direction, ray.direction);
+        let b = Float::from(2.0) * dot_product(ray.direction, sphere_to_ray);
+        let c = dot_product(sphere_to_ray, sphere_to_ray) - Float::from(1.0);
+
+        let discriminant = b.pow(2) - Float::from(4.0) * a * c;
+        if discriminant < 0.0 {
+            return Intersections::default();
+        }
+
+        let two_a = Float::from(2.0) * a;
+        Intersections::new(vec![
+            intersection((-b - discriminant.sqrt()) / two_a, self),
+            intersection((-b + discriminant.sqrt()) / two_a, self),
+        ])
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        bounding_box(point(-1.0, -1.0, -1.0), point(1.0, 1.0, 1.0))
+    }
+
+    fn local_normal_at(&self, object_point: Point) -> Vector {
+        object_point - point(0.0, 0.0, 0.0)
+    }
+}
@@ -0,0 +1,129 @@
+use crate::float::{Float, EPSILON};
+use crate::geometry::{
+    cross_product, dot_product, magnitude, normalize, BoundingBox, Point, Ray, Vector,
+};
+use crate::shapes::{intersection_with_uv, Intersections, Shape, ShapeProperties};
+
+/// Distance and barycentric `u`, `v` where a ray crosses the triangle with corner `p1`
+/// and edges `e1`, `e2`, using the Möller–Trumbore algorithm
+///
+/// a ray parallel to the triangle's plane misses
+pub(crate) fn intersect_triangle(
+    ray: &Ray,
+    p1: Point,
+    e1: Vector,
+    e2: Vector,
+) -> Option<(Float, Float, Float)> {
+    let dir_cross_e2 = cross_product(ray.direction, e2);
+    let determinant = dot_product(e1, dir_cross_e2);
+    // the determinant grows with the triangle's size and the ray's length,
+    // so the tolerance must too or small triangles are never hit
+    let tolerance = magnitude(e1) * magnitude(e2) * magnitude(ray.direction) * Float::from(EPSILON);
+    if determinant.abs() < tolerance {
+        return None;
+    }
+
+    let f = Float::from(1.0) / determinant;
+    let p1_to_origin = ray.origin - p1;
+    let u = f * dot_product(p1_to_origin, dir_cross_e2);
+    if !(0.0..=1.0).contains(&u.to_number()) {
+        return None;
+    }
+
+    let origin_cross_e1 = cross_product(p1_to_origin, e1);
+    let v = f * dot_product(ray.direction, origin_cross_e1);
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    Some((f * dot_product(e2, origin_cross_e1), u, v))
+}
+
+/// box around the three corners of a triangle
+pub(crate) fn triangle_bounds(p1: Point, p2: Point, p3: Point) -> BoundingBox {
+    let mut bounds = BoundingBox::default();
+    for corner in [p1, p2, p3] {
+        bounds.add_point(corner);
+    }
+    bounds
+}
+
+/// Flat triangle between three points of its object space
+///
+/// the edges and normal are derived from the corners so the corners can only be set on creation
+#[derive(Clone, Debug)]
+pub struct Triangle {
+    properties: ShapeProperties,
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    e1: Vector,
+    e2: Vector,
+    normal: Vector,
+}
+
+impl Triangle {
+    pub fn new(p1: Point, p2: Point, p3: Point) -> Self {
+        let e1 = p2 - p1;
+        let e2 = p3 - p1;
+        Triangle {
+            properties: ShapeProperties::default(),
+            p1,
+            p2,
+            p3,
+            e1,
+            e2,
+            normal: normalize(cross_product(e2, e1)),
+        }
+    }
+
+    pub fn p1(&self) -> Point {
+        self.p1
+    }
+
+    pub fn p2(&self) -> Point {
+        self.p2
+    }
+
+    pub fn p3(&self) -> Point {
+        self.p3
+    }
+
+    pub fn e1(&self) -> Vector {
+        self.e1
+    }
+
+    pub fn e2(&self) -> Vector {
+        self.e2
+    }
+
+    pub fn normal(&self) -> Vector {
+        self.normal
+    }
+}
+
+impl Shape for Triangle {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        match intersect_triangle(ray, self.p1, self.e1, self.e2) {
+            Some((t, u, v)) => Intersections::new(vec![intersection_with_uv(t, self, u, v)]),
+            None => Intersections::default(),
+        }
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        triangle_bounds(self.p1, self.p2, self.p3)
+    }
+
+    /// the same everywhere since the triangle is flat
+    fn local_normal_at(&self, _object_point: Point) -> Vector {
+        self.normal
+    }
+}
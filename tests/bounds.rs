@@ -0,0 +1,335 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{
+    bounding_box, normalize, point, ray, vector, BoundingBox, Matrix, Point, Ray, Vector,
+};
+use ray_tracer::shapes::{
+    cone, cube, cylinder, group, plane, sphere, Cone, Cylinder, Group, Shape,
+};
+use ray_tracer::Float;
+
+mod common;
+
+use common::{parse_number, parse_transform, parse_triple, test_shape};
+
+#[derive(Debug, WorldInit)]
+struct BoundsWorld {
+    boxes: HashMap<String, BoundingBox>,
+    points: HashMap<String, Point>,
+    direction: Option<Vector>,
+    matrix: Option<Matrix<4>>,
+    ray: Option<Ray>,
+    shapes: HashMap<String, Box<dyn Shape>>,
+    cylinders: HashMap<String, Cylinder>,
+    cones: HashMap<String, Cone>,
+    groups: HashMap<String, Group>,
+    saved_rays: HashMap<String, Arc<Mutex<Option<Ray>>>>,
+}
+
+fn parse_point(text: &str) -> Point {
+    let (x, y, z) = parse_triple(
+        text.trim()
+            .trim_start_matches("point(")
+            .trim_end_matches(')'),
+    );
+    point(x, y, z)
+}
+
+impl BoundsWorld {
+    fn bounding_box(&self, name: &str) -> BoundingBox {
+        *self
+            .boxes
+            .get(name)
+            .unwrap_or_else(|| panic!("Bounding box {} not created", name))
+    }
+
+    fn shape(&self, name: &str) -> &dyn Shape {
+        if let Some(shape) = self.shapes.get(name) {
+            shape.as_ref()
+        } else if let Some(shape) = self.cylinders.get(name) {
+            shape
+        } else if let Some(shape) = self.cones.get(name) {
+            shape
+        } else {
+            self.groups
+                .get(name)
+                .unwrap_or_else(|| panic!("Shape {} not created", name))
+        }
+    }
+
+    fn shape_mut(&mut self, name: &str) -> &mut dyn Shape {
+        if let Some(shape) = self.shapes.get_mut(name) {
+            shape.as_mut()
+        } else if let Some(shape) = self.cylinders.get_mut(name) {
+            shape
+        } else if let Some(shape) = self.cones.get_mut(name) {
+            shape
+        } else {
+            self.groups
+                .get_mut(name)
+                .unwrap_or_else(|| panic!("Shape {} not created", name))
+        }
+    }
+
+    fn take_shape(&mut self, name: &str) -> Box<dyn Shape> {
+        if let Some(shape) = self.shapes.remove(name) {
+            shape
+        } else if let Some(shape) = self.cylinders.remove(name) {
+            Box::new(shape)
+        } else if let Some(shape) = self.cones.remove(name) {
+            Box::new(shape)
+        } else {
+            Box::new(
+                self.groups
+                    .remove(name)
+                    .unwrap_or_else(|| panic!("Shape {} not created", name)),
+            )
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl World for BoundsWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            boxes: HashMap::new(),
+            points: HashMap::new(),
+            direction: Option::None,
+            matrix: Option::None,
+            ray: Option::None,
+            shapes: HashMap::new(),
+            cylinders: HashMap::new(),
+            cones: HashMap::new(),
+            groups: HashMap::new(),
+            saved_rays: HashMap::new(),
+        })
+    }
+}
+
+#[given(regex = r"^(\w+) ← bounding_box\(empty\)$")]
+fn create_empty_box(world: &mut BoundsWorld, name: String) {
+    world.boxes.insert(name, BoundingBox::default());
+}
+
+#[given(regex = r"^(\w+) ← bounding_box\(min=(point\([^)]*\)) max=(point\([^)]*\))\)$")]
+fn create_box(world: &mut BoundsWorld, name: String, min: String, max: String) {
+    world
+        .boxes
+        .insert(name, bounding_box(parse_point(&min), parse_point(&max)));
+}
+
+#[given(regex = r"^(p\d?) ← (point\([^)]*\))$")]
+fn create_point(world: &mut BoundsWorld, name: String, value: String) {
+    world.points.insert(name, parse_point(&value));
+}
+
+#[given(regex = r"^matrix ← (.+)$")]
+fn create_matrix(world: &mut BoundsWorld, expression: String) {
+    world.matrix = Some(parse_transform(&expression));
+}
+
+#[given(regex = r"^direction ← normalize\(vector\(([^)]*)\)\)$")]
+fn create_direction(world: &mut BoundsWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.direction = Some(normalize(vector(x, y, z)));
+}
+
+#[given(regex = r"^r ← ray\(point\(([^)]*)\), direction\)$")]
+fn create_ray_with_direction(world: &mut BoundsWorld, origin: String) {
+    let (x, y, z) = parse_triple(&origin);
+    let direction = world
+        .direction
+        .unwrap_or_else(|| panic!("Direction not created"));
+    world.ray = Some(ray(point(x, y, z), direction));
+}
+
+#[given(regex = r"^r ← ray\(point\(([^)]*)\), vector\(([^)]*)\)\)$")]
+fn create_ray(world: &mut BoundsWorld, origin: String, direction: String) {
+    let (x, y, z) = parse_triple(&origin);
+    let (dx, dy, dz) = parse_triple(&direction);
+    world.ray = Some(ray(point(x, y, z), vector(dx, dy, dz)));
+}
+
+#[given(regex = r"^(\w+) ← (sphere|plane|cube|cylinder|cone|test_shape|group)\(\)$")]
+fn create_shape(world: &mut BoundsWorld, name: String, kind: String) {
+    match kind.as_str() {
+        "cylinder" => {
+            world.cylinders.insert(name, cylinder());
+        }
+        "cone" => {
+            world.cones.insert(name, cone());
+        }
+        "group" => {
+            world.groups.insert(name, group());
+        }
+        "test_shape" => {
+            let shape = test_shape();
+            world
+                .saved_rays
+                .insert(name.clone(), shape.saved_ray.clone());
+            world.shapes.insert(name, Box::new(shape));
+        }
+        _ => {
+            let shape: Box<dyn Shape> = match kind.as_str() {
+                "sphere" => Box::new(sphere()),
+                "plane" => Box::new(plane()),
+                _ => Box::new(cube()),
+            };
+            world.shapes.insert(name, shape);
+        }
+    }
+}
+
+#[given(regex = r"^(\w+).(minimum|maximum) ← (.+)$")]
+fn set_shape_limit(world: &mut BoundsWorld, name: String, limit: String, value: String) {
+    let value = Float::from(parse_number(&value));
+    let (minimum, maximum) = if let Some(c) = world.cylinders.get_mut(&name) {
+        (&mut c.minimum, &mut c.maximum)
+    } else {
+        let c = world
+            .cones
+            .get_mut(&name)
+            .unwrap_or_else(|| panic!("Cylinder or cone {} not created", name));
+        (&mut c.minimum, &mut c.maximum)
+    };
+    match limit.as_str() {
+        "minimum" => *minimum = value,
+        _ => *maximum = value,
+    }
+}
+
+#[given(regex = r"^set_transform\((\w+), (.+)\)$")]
+fn set_shape_transform(world: &mut BoundsWorld, name: String, expression: String) {
+    world
+        .shape_mut(&name)
+        .set_transform(parse_transform(&expression));
+}
+
+#[given(regex = r"^add_child\((\w+), (\w+)\)$")]
+fn add_child(world: &mut BoundsWorld, parent: String, child: String) {
+    let child = world.take_shape(&child);
+    world
+        .groups
+        .get_mut(&parent)
+        .unwrap_or_else(|| panic!("Group {} not created", parent))
+        .add_child(child);
+}
+
+#[when(regex = r"^(p\d) is added to (\w+)$")]
+fn when_point_added(world: &mut BoundsWorld, point: String, name: String) {
+    let p = world.points[&point];
+    world
+        .boxes
+        .get_mut(&name)
+        .unwrap_or_else(|| panic!("Bounding box {} not created", name))
+        .add_point(p);
+}
+
+#[when(regex = r"^box ← (bounds_of|parent_space_bounds_of)\((\w+)\)$")]
+fn when_bounds_queried(world: &mut BoundsWorld, function: String, name: String) {
+    let shape = world.shape(&name);
+    let bounds = match function.as_str() {
+        "bounds_of" => shape.bounds(),
+        _ => shape.parent_space_bounds(),
+    };
+    world.boxes.insert("box".to_string(), bounds);
+}
+
+#[when(regex = r"^box ← union\((\w+), (\w+)\)$")]
+fn when_boxes_combined(world: &mut BoundsWorld, lhs: String, rhs: String) {
+    let union = world.bounding_box(&lhs).union(&world.bounding_box(&rhs));
+    world.boxes.insert("box".to_string(), union);
+}
+
+#[when(regex = r"^box2 ← transform\(box, matrix\)$")]
+fn when_box_transformed(world: &mut BoundsWorld) {
+    let matrix = world.matrix.unwrap_or_else(|| panic!("Matrix not created"));
+    let transformed = world.bounding_box("box").transform(matrix);
+    world.boxes.insert("box2".to_string(), transformed);
+}
+
+#[when(regex = r"^\(left, right\) ← split_bounds\(box\)$")]
+fn when_box_split(world: &mut BoundsWorld) {
+    let (left, right) = world.bounding_box("box").split();
+    world.boxes.insert("left".to_string(), left);
+    world.boxes.insert("right".to_string(), right);
+}
+
+#[when(regex = r"^xs ← intersect\((\w+), r\)$")]
+fn when_shape_intersected(world: &mut BoundsWorld, name: String) {
+    let r = world.ray.unwrap_or_else(|| panic!("Ray not created"));
+    world.shape(&name).intersect(&r);
+}
+
+#[then(regex = r"^(\w+).(min|max) = (point\([^)]*\))$")]
+fn assert_box_corner(world: &mut BoundsWorld, name: String, corner: String, value: String) {
+    let bounds = world.bounding_box(&name);
+    let actual = match corner.as_str() {
+        "min" => bounds.min,
+        _ => bounds.max,
+    };
+    assert_eq!(actual, parse_point(&value));
+}
+
+#[then(regex = r"^surface_area\(box\) = (.+)$")]
+fn assert_surface_area(world: &mut BoundsWorld, value: String) {
+    assert_eq!(
+        world.bounding_box("box").surface_area(),
+        parse_number(&value)
+    );
+}
+
+#[then(regex = r"^box_contains_point\(box, p\) is (true|false)$")]
+fn assert_box_contains_point(world: &mut BoundsWorld, result: bool) {
+    let p = world.points["p"];
+    assert_eq!(world.bounding_box("box").contains_point(p), result);
+}
+
+#[then(regex = r"^box_contains_box\(box, box2\) is (true|false)$")]
+fn assert_box_contains_box(world: &mut BoundsWorld, result: bool) {
+    let other = world.bounding_box("box2");
+    assert_eq!(world.bounding_box("box").contains_box(&other), result);
+}
+
+#[then(regex = r"^intersects\(box, r\) is (true|false)$")]
+fn assert_box_intersected(world: &mut BoundsWorld, result: bool) {
+    let r = world.ray.unwrap_or_else(|| panic!("Ray not created"));
+    assert_eq!(world.bounding_box("box").intersects(&r), result);
+}
+
+#[then(regex = r"^(\w+).saved_ray is (set|unset)$")]
+fn assert_saved_ray(world: &mut BoundsWorld, name: String, state: String) {
+    let saved_ray = world.saved_rays[&name].lock().unwrap();
+    assert_eq!(saved_ray.is_some(), state == "set");
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/bounds.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        BoundsWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<BoundsWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/bounds.feature"),
+    );
+}
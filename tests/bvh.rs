@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{point, ray, vector, Ray};
+use ray_tracer::shapes::{cube, group, plane, sphere, Group, Shape};
+use ray_tracer::Float;
+
+mod common;
+
+use common::{parse_number, parse_transform, parse_triple, test_shape};
+
+#[derive(Debug, WorldInit)]
+struct BvhWorld {
+    shapes: HashMap<String, Box<dyn Shape>>,
+    group: Option<Group>,
+    saved_rays: HashMap<String, Arc<Mutex<Option<Ray>>>>,
+    ray: Option<Ray>,
+    intersections: Vec<Float>,
+}
+
+impl BvhWorld {
+    fn group(&mut self) -> &mut Group {
+        self.group
+            .as_mut()
+            .unwrap_or_else(|| panic!("Group not created"))
+    }
+
+    /// shape at a path of child indices such as `g[0][1]`
+    fn node(&mut self, path: &str) -> &dyn Shape {
+        let mut shape: &dyn Shape = self.group();
+        for index in path
+            .trim_start_matches('g')
+            .split(']')
+            .filter(|index| !index.is_empty())
+        {
+            let index: usize = index
+                .trim_start_matches('[')
+                .parse()
+                .unwrap_or_else(|_| panic!("Invalid path: {}", path));
+            shape = shape.children()[index].as_ref();
+        }
+        shape
+    }
+}
+
+#[async_trait(?Send)]
+impl World for BvhWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            shapes: HashMap::new(),
+            group: Option::None,
+            saved_rays: HashMap::new(),
+            ray: Option::None,
+            intersections: vec![],
+        })
+    }
+}
+
+#[given(regex = r"^(\w+) ← (sphere|cube|plane|test_shape)\(\)$")]
+fn create_shape(world: &mut BvhWorld, name: String, kind: String) {
+    let shape: Box<dyn Shape> = match kind.as_str() {
+        "sphere" => Box::new(sphere()),
+        "cube" => Box::new(cube()),
+        "plane" => Box::new(plane()),
+        _ => {
+            let shape = test_shape();
+            world
+                .saved_rays
+                .insert(name.clone(), shape.saved_ray.clone());
+            Box::new(shape)
+        }
+    };
+    world.shapes.insert(name, shape);
+}
+
+#[given(regex = r"^g ← group\(\)$")]
+fn create_group(world: &mut BvhWorld) {
+    world.group = Some(group());
+}
+
+#[given(regex = r"^set_transform\((\w+), (.+)\)$")]
+fn set_shape_transform(world: &mut BvhWorld, name: String, expression: String) {
+    world
+        .shapes
+        .get_mut(&name)
+        .unwrap_or_else(|| panic!("Shape {} not created", name))
+        .set_transform(parse_transform(&expression));
+}
+
+#[given(regex = r"^add_child\(g, (\w+)\)$")]
+fn add_child(world: &mut BvhWorld, name: String) {
+    let child = world
+        .shapes
+        .remove(&name)
+        .unwrap_or_else(|| panic!("Shape {} not created", name));
+    world.group().add_child(child);
+}
+
+#[given(regex = r"^r ← ray\(point\(([^)]*)\), vector\(([^)]*)\)\)$")]
+fn create_ray(world: &mut BvhWorld, origin: String, direction: String) {
+    let (x, y, z) = parse_triple(&origin);
+    let (dx, dy, dz) = parse_triple(&direction);
+    world.ray = Some(ray(point(x, y, z), vector(dx, dy, dz)));
+}
+
+#[when(regex = r"^divide\(g, (\d+)\)$")]
+fn when_group_divided(world: &mut BvhWorld, threshold: usize) {
+    world.group().divide(threshold);
+}
+
+#[when(regex = r"^xs ← intersect\(g, r\)$")]
+fn when_group_intersected(world: &mut BvhWorld) {
+    let r = world.ray.unwrap_or_else(|| panic!("Ray not created"));
+    world.intersections = world.group().intersect(&r).iter().map(|i| i.t).collect();
+}
+
+#[then(regex = r"^(g(?:\[\d+\])*).count = (\d+)$")]
+fn assert_child_count(world: &mut BvhWorld, path: String, count: usize) {
+    assert_eq!(world.node(&path).children().len(), count);
+}
+
+#[then(regex = r"^bounds_of\((g(?:\[\d+\])*)\).(min|max) = point\(([^)]*)\)$")]
+fn assert_bounds(world: &mut BvhWorld, path: String, corner: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    let bounds = world.node(&path).bounds();
+    let actual = match corner.as_str() {
+        "min" => bounds.min,
+        _ => bounds.max,
+    };
+    assert_eq!(actual, point(x, y, z));
+}
+
+#[then(regex = r"^xs.count = (\d+)$")]
+fn assert_intersection_count(world: &mut BvhWorld, count: usize) {
+    assert_eq!(world.intersections.len(), count);
+}
+
+#[then(regex = r"^xs\[(\d+)\].t = (.+)$")]
+fn assert_intersection_distance(world: &mut BvhWorld, index: usize, t: String) {
+    assert_eq!(world.intersections[index], parse_number(&t));
+}
+
+#[then(regex = r"^(\w+).saved_ray is (set|unset)$")]
+fn assert_saved_ray(world: &mut BvhWorld, name: String, state: String) {
+    let saved_ray = world.saved_rays[&name].lock().unwrap();
+    assert_eq!(saved_ray.is_some(), state == "set");
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/bvh.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        BvhWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<BvhWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/bvh.feature"),
+    );
+}
@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::gherkin::Step;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{identity_matrix, point, vector, view_transform, Point, Ray, Vector};
+use ray_tracer::graphics::{canvas_to_ppm, color, pixel_at, Canvas};
+use ray_tracer::scene::{self, camera, default_world, Camera};
+
+mod common;
+
+use common::{parse_number, parse_transform, parse_triple};
+
+#[derive(Debug, WorldInit)]
+struct CameraWorld {
+    numbers: HashMap<String, f64>,
+    points: HashMap<String, Point>,
+    vectors: HashMap<String, Vector>,
+    camera: Option<Camera>,
+    world: Option<scene::World>,
+    ray: Option<Ray>,
+    images: HashMap<String, Canvas>,
+    ppm: Option<String>,
+}
+
+impl CameraWorld {
+    fn camera(&mut self) -> &mut Camera {
+        self.camera
+            .as_mut()
+            .unwrap_or_else(|| panic!("Camera not created"))
+    }
+
+    fn ray(&self) -> Ray {
+        self.ray.unwrap_or_else(|| panic!("Ray not created"))
+    }
+
+    fn image(&self, name: &str) -> &Canvas {
+        self.images
+            .get(name)
+            .unwrap_or_else(|| panic!("Image {} not rendered", name))
+    }
+
+    fn number(&self, value: &str) -> f64 {
+        match self.numbers.get(value) {
+            Some(number) => *number,
+            None => parse_number(value),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl World for CameraWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            numbers: HashMap::new(),
+            points: HashMap::new(),
+            vectors: HashMap::new(),
+            camera: Option::None,
+            world: Option::None,
+            ray: Option::None,
+            images: HashMap::new(),
+            ppm: Option::None,
+        })
+    }
+}
+
+#[given(regex = r"^(hsize|vsize|field_of_view) ← (.+)$")]
+fn create_number(world: &mut CameraWorld, name: String, value: String) {
+    world.numbers.insert(name, parse_number(&value));
+}
+
+#[given(regex = r"^(\w+) ← (point|vector)\(([^)]*)\)$")]
+fn create_tuple(world: &mut CameraWorld, name: String, kind: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    match kind.as_str() {
+        "point" => {
+            world.points.insert(name, point(x, y, z));
+        }
+        _ => {
+            world.vectors.insert(name, vector(x, y, z));
+        }
+    }
+}
+
+#[given(regex = r"^w ← default_world\(\)$")]
+fn create_default_world(world: &mut CameraWorld) {
+    world.world = Some(default_world());
+}
+
+#[given(regex = r"^c ← camera\(([^,]+), ([^,]+), ([^,]+)\)$")]
+#[when(regex = r"^c ← camera\(([^,]+), ([^,]+), ([^,]+)\)$")]
+fn create_camera(world: &mut CameraWorld, hsize: String, vsize: String, field_of_view: String) {
+    world.camera = Some(camera(
+        world.number(&hsize) as usize,
+        world.number(&vsize) as usize,
+        world.number(&field_of_view),
+    ));
+}
+
+#[given(regex = r"^c.transform ← view_transform\((\w+), (\w+), (\w+)\)$")]
+fn set_camera_view_transform(world: &mut CameraWorld, from: String, to: String, up: String) {
+    let transform = view_transform(world.points[&from], world.points[&to], world.vectors[&up]);
+    world.camera().set_transform(transform);
+}
+
+#[when(regex = r"^c.transform ← ((?:translation|scaling|rotation_[xyz]|shearing).*)$")]
+fn when_camera_transformed(world: &mut CameraWorld, expression: String) {
+    world.camera().set_transform(parse_transform(&expression));
+}
+
+#[when(regex = r"^r ← ray_for_pixel\(c, (\d+), (\d+)\)$")]
+fn when_ray_for_pixel(world: &mut CameraWorld, px: usize, py: usize) {
+    world.ray = Some(world.camera().ray_for_pixel(px, py));
+}
+
+#[given(regex = r"^c.samples ← (\d+)$")]
+#[when(regex = r"^c.samples ← (\d+)$")]
+fn when_sample_count_set(world: &mut CameraWorld, samples: usize) {
+    world.camera().set_samples(samples);
+}
+
+#[when(regex = r"^c.threads ← (\d+)$")]
+fn when_thread_count_set(world: &mut CameraWorld, threads: usize) {
+    world.camera().set_threads(threads);
+}
+
+#[when(regex = r"^(\w+) ← render\(c, w\)$")]
+fn when_rendered(world: &mut CameraWorld, name: String) {
+    let scene = world
+        .world
+        .as_ref()
+        .unwrap_or_else(|| panic!("World not created"));
+    let camera = world.camera.unwrap_or_else(|| panic!("Camera not created"));
+    let image = camera.render(scene);
+    world.images.insert(name, image);
+}
+
+#[when(regex = r"^ppm ← canvas_to_ppm\(image\)$")]
+fn when_written_to_ppm(world: &mut CameraWorld) {
+    world.ppm = Some(canvas_to_ppm(world.image("image")));
+}
+
+#[then(regex = r"^c.(hsize|vsize) = (\d+)$")]
+fn assert_camera_size(world: &mut CameraWorld, property: String, size: usize) {
+    let camera = world.camera();
+    let actual = match property.as_str() {
+        "hsize" => camera.hsize(),
+        _ => camera.vsize(),
+    };
+    assert_eq!(actual, size);
+}
+
+#[then(regex = r"^c.samples = (\d+)$")]
+fn assert_camera_samples(world: &mut CameraWorld, samples: usize) {
+    assert_eq!(world.camera().samples(), samples);
+}
+
+#[then(regex = r"^c.field_of_view = (.+)$")]
+fn assert_camera_field_of_view(world: &mut CameraWorld, value: String) {
+    assert_eq!(world.camera().field_of_view(), parse_number(&value));
+}
+
+#[then(regex = r"^c.transform = identity_matrix$")]
+fn assert_camera_default_transform(world: &mut CameraWorld) {
+    assert_eq!(world.camera().transform(), identity_matrix());
+}
+
+#[then(regex = r"^c.pixel_size = (.+)$")]
+fn assert_pixel_size(world: &mut CameraWorld, value: String) {
+    assert_eq!(world.camera().pixel_size(), parse_number(&value));
+}
+
+#[then(regex = r"^r.origin = point\(([^)]*)\)$")]
+fn assert_ray_origin(world: &mut CameraWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.ray().origin, point(x, y, z));
+}
+
+#[then(regex = r"^r.direction = vector\(([^)]*)\)$")]
+fn assert_ray_direction(world: &mut CameraWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.ray().direction, vector(x, y, z));
+}
+
+#[then(regex = r"^pixel_at\((\w+), (\d+), (\d+)\) = color\(([^)]*)\)$")]
+fn assert_pixel(world: &mut CameraWorld, name: String, x: usize, y: usize, values: String) {
+    let (red, green, blue) = parse_triple(&values);
+    assert_eq!(pixel_at(world.image(&name), x, y), color(red, green, blue));
+}
+
+#[then(regex = r"^(\w+) = (\w+)$")]
+fn assert_images_equal(world: &mut CameraWorld, lhs: String, rhs: String) {
+    assert_eq!(world.image(&lhs).pixels, world.image(&rhs).pixels);
+}
+
+#[then(regex = r"^(\w+) ≠ (\w+)$")]
+fn assert_images_differ(world: &mut CameraWorld, lhs: String, rhs: String) {
+    assert_ne!(world.image(&lhs).pixels, world.image(&rhs).pixels);
+}
+
+#[then(regex = r"^lines 1-3 of ppm are$")]
+fn assert_ppm_header(world: &mut CameraWorld, step: &Step) {
+    let ppm = world
+        .ppm
+        .as_ref()
+        .unwrap_or_else(|| panic!("Failed to get PPM output"));
+    let docstring = step
+        .docstring()
+        .unwrap_or_else(|| panic!("Missing docstring"));
+
+    assert!(ppm.starts_with(docstring.trim()));
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/camera.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        CameraWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<CameraWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/camera.feature"),
+    );
+}
@@ -4,13 +4,16 @@ use async_trait::async_trait;
 use cucumber::gherkin::Step;
 use cucumber::{given, then, when, World, WorldInit};
 
-use ray_tracer::graphics::{canvas, canvas_to_ppm, color, pixel_at, write_pixel, Canvas, Color};
+use ray_tracer::graphics::{
+    canvas, canvas_to_png, canvas_to_ppm, color, pixel_at, write_pixel, Canvas, Color,
+};
 
 #[derive(Debug, WorldInit)]
 struct CanvasWorld {
     canvas: Option<Canvas>,
     paint_colors: Vec<Color>,
     output: Option<String>,
+    png: Option<Vec<u8>>,
 }
 
 #[async_trait(?Send)]
@@ -22,6 +25,7 @@ impl World for CanvasWorld {
             canvas: Option::None,
             paint_colors: Vec::with_capacity(3),
             output: Option::None,
+            png: Option::None,
         })
     }
 }
@@ -71,6 +75,15 @@ fn when_write_to_ppm(world: &mut CanvasWorld) {
     world.output = Some(canvas_to_ppm(canvas));
 }
 
+#[when(regex = r"^png ← canvas_to_png\(c\)$")]
+fn when_write_to_png(world: &mut CanvasWorld) {
+    let canvas = world
+        .canvas
+        .as_ref()
+        .unwrap_or_else(|| panic!("Canvas not created"));
+    world.png = Some(canvas_to_png(canvas));
+}
+
 #[then(regex = r"^c.(\w+) = (\d+)$")]
 fn assert_canvas_size(world: &mut CanvasWorld, dimension: String, value: usize) {
     let canvas = world
@@ -110,7 +123,7 @@ fn assert_pixel_painted(world: &mut CanvasWorld, x: usize, y: usize) {
 
     let paint_color = *world
         .paint_colors
-        .get(0)
+        .first()
         .unwrap_or_else(|| panic!("Color not parsed correctly"));
 
     assert_eq!(pixel_at(canvas, x, y), paint_color);
@@ -162,6 +175,32 @@ fn assert_ppm_body_ends_with_newline(world: &mut CanvasWorld) {
     assert!(actual_ppm_output.ends_with('\n'));
 }
 
+#[then(regex = r"^png decodes to (\d+)x(\d+) pixels starting with$")]
+fn assert_png_pixels(world: &mut CanvasWorld, width: u32, height: u32, step: &Step) {
+    let png_file = world
+        .png
+        .as_ref()
+        .unwrap_or_else(|| panic!("Failed to get PNG output"));
+    let mut reader = png::Decoder::new(png_file.as_slice())
+        .read_info()
+        .unwrap_or_else(|error| panic!("Invalid PNG: {}", error));
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut pixels)
+        .unwrap_or_else(|error| panic!("Invalid PNG: {}", error));
+
+    let expected = step
+        .docstring()
+        .unwrap_or_else(|| panic!("Missing docstring"))
+        .split_whitespace()
+        .map(|channel| channel.parse::<u8>().unwrap())
+        .collect::<Vec<u8>>();
+
+    assert_eq!((info.width, info.height), (width, height));
+    assert_eq!(info.color_type, png::ColorType::Rgb);
+    assert!(pixels.starts_with(&expected));
+}
+
 fn main() {
     use cucumber::{writer, WriterExt as _};
     use std::fs;
@@ -0,0 +1,151 @@
+use std::convert::Infallible;
+use std::path::PathBuf;
+use std::process::{self, Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{env, fs};
+
+use async_trait::async_trait;
+use cucumber::gherkin::Step;
+use cucumber::{given, then, when, World, WorldInit};
+
+/// gives every scenario its own directory so they can run concurrently
+static SCENARIOS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, WorldInit)]
+struct CliWorld {
+    directory: PathBuf,
+    output: Option<Output>,
+}
+
+impl CliWorld {
+    fn output(&self) -> &Output {
+        self.output
+            .as_ref()
+            .unwrap_or_else(|| panic!("Command not run"))
+    }
+}
+
+#[async_trait(?Send)]
+impl World for CliWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        let directory = env::temp_dir().join(format!(
+            "ray-tracer-cli-{}-{}",
+            process::id(),
+            SCENARIOS.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&directory).unwrap();
+        Ok(Self {
+            directory,
+            output: Option::None,
+        })
+    }
+}
+
+impl Drop for CliWorld {
+    fn drop(&mut self) {
+        fs::remove_dir_all(&self.directory).unwrap_or(());
+    }
+}
+
+/// escapes used in the feature file, e.g. `\n` and `\x89`
+fn unescape(value: &str) -> Vec<u8> {
+    let mut bytes = vec![];
+    let mut rest = value;
+    while let Some(index) = rest.find('\\') {
+        bytes.extend_from_slice(&rest.as_bytes()[..index]);
+        rest = &rest[index + 1..];
+        if let Some(hex) = rest.strip_prefix('x') {
+            bytes.push(u8::from_str_radix(&hex[..2], 16).unwrap());
+            rest = &hex[2..];
+        } else {
+            bytes.push(match rest.as_bytes()[0] {
+                b'n' => b'\n',
+                other => other,
+            });
+            rest = &rest[1..];
+        }
+    }
+    bytes.extend_from_slice(rest.as_bytes());
+    bytes
+}
+
+#[given(regex = r#"^a scene file "(.+)" containing:$"#)]
+fn create_scene_file(world: &mut CliWorld, name: String, step: &Step) {
+    let docstring = step
+        .docstring()
+        .unwrap_or_else(|| panic!("Missing docstring"));
+    fs::write(world.directory.join(name), docstring.trim_start()).unwrap();
+}
+
+#[when(regex = r#"^I run "ray-tracer ?(.*)"$"#)]
+fn when_command_run(world: &mut CliWorld, args: String) {
+    world.output = Some(
+        Command::new(env!("CARGO_BIN_EXE_ray-tracer"))
+            .args(args.split_whitespace())
+            .current_dir(&world.directory)
+            .output()
+            .unwrap_or_else(|error| panic!("Failed to run ray-tracer: {}", error)),
+    );
+}
+
+#[then(regex = r"^the command succeeds$")]
+fn assert_success(world: &mut CliWorld) {
+    let output = world.output();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[then(regex = r"^the command fails with exit code (\d+)$")]
+fn assert_failure(world: &mut CliWorld, code: i32) {
+    assert_eq!(world.output().status.code(), Some(code));
+}
+
+#[then(regex = r#"^the (output|error) mentions "(.+)"$"#)]
+fn assert_mentions(world: &mut CliWorld, stream: String, text: String) {
+    let output = world.output();
+    let contents = match stream.as_str() {
+        "output" => &output.stdout,
+        _ => &output.stderr,
+    };
+    let contents = String::from_utf8_lossy(contents);
+    assert!(contents.contains(&text), "{}", contents);
+}
+
+#[then(regex = r#"^"(.+)" starts with "(.+)"$"#)]
+fn assert_file_start(world: &mut CliWorld, name: String, prefix: String) {
+    let contents = fs::read(world.directory.join(name)).unwrap();
+    assert!(contents.starts_with(&unescape(&prefix)));
+}
+
+#[then(regex = r#"^"(.+)" does not exist$"#)]
+fn assert_missing_file(world: &mut CliWorld, name: String) {
+    assert!(!world.directory.join(name).exists());
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/cli.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        CliWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<CliWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/cli.feature"),
+    );
+}
@@ -0,0 +1,205 @@
+#![allow(dead_code)]
+
+use std::f64::consts::PI;
+use std::sync::{Arc, Mutex};
+
+use cucumber::gherkin::Step;
+
+use ray_tracer::geometry::{
+    bounding_box, identity_matrix, point, rotation_x, rotation_y, rotation_z, scaling, shearing,
+    translation, vector, BoundingBox, Matrix, Point, Ray, Vector,
+};
+use ray_tracer::graphics::{color, Color};
+use ray_tracer::patterns::{Pattern, PatternProperties};
+use ray_tracer::shapes::{Intersections, Shape, ShapeProperties};
+use ray_tracer::Float;
+
+/// Pattern that returns its pattern space point as a color
+#[derive(Debug, Default)]
+pub struct TestPattern {
+    properties: PatternProperties,
+}
+
+impl Pattern for TestPattern {
+    fn properties(&self) -> &PatternProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut PatternProperties {
+        &mut self.properties
+    }
+
+    fn pattern_at(&self, point: Point) -> Color {
+        color(
+            point.x.to_number(),
+            point.y.to_number(),
+            point.z.to_number(),
+        )
+    }
+}
+
+pub fn test_pattern() -> TestPattern {
+    TestPattern::default()
+}
+
+/// Shape that records the object space ray it was intersected with
+///
+/// the record is shared so it can still be read after the shape is moved into a group
+#[derive(Debug, Default)]
+pub struct TestShape {
+    properties: ShapeProperties,
+    pub saved_ray: Arc<Mutex<Option<Ray>>>,
+}
+
+impl Shape for TestShape {
+    fn properties(&self) -> &ShapeProperties {
+        &self.properties
+    }
+
+    fn properties_mut(&mut self) -> &mut ShapeProperties {
+        &mut self.properties
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Intersections<'_> {
+        *self.saved_ray.lock().unwrap() = Some(*ray);
+        Intersections::default()
+    }
+
+    fn local_normal_at(&self, object_point: Point) -> Vector {
+        vector(
+            object_point.x.to_number(),
+            object_point.y.to_number(),
+            object_point.z.to_number(),
+        )
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        bounding_box(point(-1.0, -1.0, -1.0), point(1.0, 1.0, 1.0))
+    }
+}
+
+pub fn test_shape() -> TestShape {
+    TestShape::default()
+}
+
+/// identifies a shape even after it has been moved into a group
+pub fn address(shape: &dyn Shape) -> usize {
+    shape as *const dyn Shape as *const () as usize
+}
+
+/// `shape` or the shape it contains at `target`
+pub fn find(shape: &dyn Shape, target: usize) -> Option<&dyn Shape> {
+    if address(shape) == target {
+        return Some(shape);
+    }
+    shape
+        .children()
+        .iter()
+        .find_map(|child| find(child.as_ref(), target))
+}
+
+fn parse_term(term: &str) -> f64 {
+    let term = term.trim();
+    if term == "π" {
+        PI
+    } else if let Some(radicand) = term.strip_prefix('√') {
+        parse_term(radicand).sqrt()
+    } else {
+        term.parse::<f64>()
+            .unwrap_or_else(|_| panic!("Invalid number: {}", term))
+    }
+}
+
+/// parse numbers written in the book's notation
+pub fn parse_number(value: &str) -> f64 {
+    let value = value.trim();
+    if let Some(value) = value.strip_prefix('-') {
+        return -parse_number(value);
+    }
+
+    match value.split_once('/') {
+        Some((numerator, denominator)) => parse_term(numerator) / parse_term(denominator),
+        None => parse_term(value),
+    }
+}
+
+/// parse a comma separated list of numbers e.g. `1, √2/2, -3`
+pub fn parse_numbers(values: &str) -> Vec<f64> {
+    values.split(',').map(parse_number).collect()
+}
+
+/// parse a comma separated list of exactly three numbers
+pub fn parse_triple(values: &str) -> (f64, f64, f64) {
+    match parse_numbers(values)[..] {
+        [x, y, z] => (x, y, z),
+        _ => panic!("Expected three values: {}", values),
+    }
+}
+
+/// parse a product of transformations e.g. `scaling(1, 0.5, 1) * rotation_z(π/5)`
+pub fn parse_transform(expression: &str) -> Matrix<4> {
+    expression
+        .split(" * ")
+        .map(|term| {
+            let (name, arguments) = term
+                .trim()
+                .trim_end_matches(')')
+                .split_once('(')
+                .unwrap_or((term.trim(), ""));
+            let arguments = if arguments.is_empty() {
+                vec![]
+            } else {
+                parse_numbers(arguments)
+            };
+
+            match (name, &arguments[..]) {
+                ("identity_matrix", []) => identity_matrix(),
+                ("translation", [x, y, z]) => translation(*x, *y, *z),
+                ("scaling", [x, y, z]) => scaling(*x, *y, *z),
+                ("rotation_x", [radians]) => rotation_x(*radians),
+                ("rotation_y", [radians]) => rotation_y(*radians),
+                ("rotation_z", [radians]) => rotation_z(*radians),
+                ("shearing", [x_y, x_z, y_x, y_z, z_x, z_y]) => {
+                    shearing(*x_y, *x_z, *y_x, *y_z, *z_x, *z_y)
+                }
+                _ => panic!("Unknown transformation: {}", term),
+            }
+        })
+        .reduce(|product, transform| product * transform)
+        .unwrap_or_else(identity_matrix)
+}
+
+/// apply the `| key | value |` rows of a `shape ← sphere() with:` table
+pub fn apply_shape_attributes(shape: &mut dyn Shape, step: &Step) {
+    let table = step
+        .table
+        .as_ref()
+        .unwrap_or_else(|| panic!("Missing shape attribute table"));
+
+    let mut material = shape.material().clone();
+    for row in &table.rows {
+        let (key, value) = (row[0].trim(), row[1].trim());
+        match key {
+            "transform" => shape.set_transform(parse_transform(value)),
+            "material.color" => {
+                let (red, green, blue) =
+                    parse_triple(value.trim_start_matches('(').trim_end_matches(')'));
+                material.color = color(red, green, blue);
+            }
+            "material.ambient" => material.ambient = Float::from(parse_number(value)),
+            "material.diffuse" => material.diffuse = Float::from(parse_number(value)),
+            "material.specular" => material.specular = Float::from(parse_number(value)),
+            "material.shininess" => material.shininess = Float::from(parse_number(value)),
+            "material.reflective" => material.reflective = Float::from(parse_number(value)),
+            "material.transparency" => material.transparency = Float::from(parse_number(value)),
+            "material.refractive_index" => {
+                material.refractive_index = Float::from(parse_number(value))
+            }
+            "material.pattern" if value == "test_pattern()" => {
+                material.pattern = Some(Arc::new(test_pattern()))
+            }
+            _ => panic!("Unknown shape attribute: {}", key),
+        }
+    }
+    shape.set_material(material);
+}
@@ -0,0 +1,166 @@
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{normalize, point, ray, vector, Ray, Vector};
+use ray_tracer::shapes::{cone, Cone, Shape};
+use ray_tracer::Float;
+
+mod common;
+
+use common::{parse_number, parse_transform, parse_triple};
+
+#[derive(Debug, WorldInit)]
+struct ConeWorld {
+    cone: Option<Cone>,
+    direction: Option<Vector>,
+    ray: Option<Ray>,
+    intersections: Vec<Float>,
+    normal: Option<Vector>,
+}
+
+impl ConeWorld {
+    fn cone(&mut self) -> &mut Cone {
+        self.cone
+            .as_mut()
+            .unwrap_or_else(|| panic!("Cone not created"))
+    }
+}
+
+#[async_trait(?Send)]
+impl World for ConeWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            cone: Option::None,
+            direction: Option::None,
+            ray: Option::None,
+            intersections: vec![],
+            normal: Option::None,
+        })
+    }
+}
+
+#[given(regex = r"^shape ← cone\(\)$")]
+fn create_cone(world: &mut ConeWorld) {
+    world.cone = Some(cone());
+}
+
+#[given(regex = r"^shape.(minimum|maximum) ← (.+)$")]
+fn set_cone_bound(world: &mut ConeWorld, property: String, value: String) {
+    let value = Float::from(parse_number(&value));
+    match property.as_str() {
+        "minimum" => world.cone().minimum = value,
+        _ => world.cone().maximum = value,
+    }
+}
+
+#[given(regex = r"^shape.closed ← (true|false)$")]
+fn set_cone_closed(world: &mut ConeWorld, closed: bool) {
+    world.cone().closed = closed;
+}
+
+#[given(regex = r"^shape.transform ← (.+)$")]
+fn set_cone_transform(world: &mut ConeWorld, expression: String) {
+    world.cone().set_transform(parse_transform(&expression));
+}
+
+#[given(regex = r"^direction ← normalize\(vector\(([^)]*)\)\)$")]
+fn create_direction(world: &mut ConeWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.direction = Some(normalize(vector(x, y, z)));
+}
+
+#[given(regex = r"^direction ← vector\(([^)]*)\)$")]
+fn create_unnormalized_direction(world: &mut ConeWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.direction = Some(vector(x, y, z));
+}
+
+#[given(regex = r"^r ← ray\(point\(([^)]*)\), direction\)$")]
+fn create_ray(world: &mut ConeWorld, origin: String) {
+    let (x, y, z) = parse_triple(&origin);
+    let direction = world
+        .direction
+        .unwrap_or_else(|| panic!("Direction not created"));
+    world.ray = Some(ray(point(x, y, z), direction));
+}
+
+#[when(regex = r"^xs ← local_intersect\(shape, r\)$")]
+fn when_cone_intersected(world: &mut ConeWorld) {
+    let r = world.ray.unwrap_or_else(|| panic!("Ray not created"));
+    world.intersections = world
+        .cone()
+        .local_intersect(&r)
+        .iter()
+        .map(|i| i.t)
+        .collect();
+}
+
+#[when(regex = r"^xs ← intersect\(shape, r\)$")]
+fn when_transformed_cone_intersected(world: &mut ConeWorld) {
+    let r = world.ray.unwrap_or_else(|| panic!("Ray not created"));
+    world.intersections = world.cone().intersect(&r).iter().map(|i| i.t).collect();
+}
+
+#[when(regex = r"^n ← local_normal_at\(shape, point\(([^)]*)\)\)$")]
+fn when_normal_computed(world: &mut ConeWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.normal = Some(world.cone().local_normal_at(point(x, y, z)));
+}
+
+#[then(regex = r"^xs.count = (\d+)$")]
+fn assert_intersection_count(world: &mut ConeWorld, count: usize) {
+    assert_eq!(world.intersections.len(), count);
+}
+
+#[then(regex = r"^xs\[(\d+)\].t = (.+)$")]
+fn assert_intersection_distance(world: &mut ConeWorld, index: usize, t: String) {
+    assert_eq!(world.intersections[index], parse_number(&t));
+}
+
+#[then(regex = r"^n = vector\(([^)]*)\)$")]
+fn assert_normal(world: &mut ConeWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.normal, Some(vector(x, y, z)));
+}
+
+#[then(regex = r"^shape.(minimum|maximum) = (.+)$")]
+fn assert_cone_bound(world: &mut ConeWorld, property: String, value: String) {
+    let actual = match property.as_str() {
+        "minimum" => world.cone().minimum,
+        _ => world.cone().maximum,
+    };
+    assert_eq!(actual, parse_number(&value));
+}
+
+#[then(regex = r"^shape.closed = (true|false)$")]
+fn assert_cone_closed(world: &mut ConeWorld, closed: bool) {
+    assert_eq!(world.cone().closed, closed);
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/cones.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        ConeWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<ConeWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/cones.feature"),
+    );
+}
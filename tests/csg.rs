@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{point, ray, vector, BoundingBox, Ray, Vector};
+use ray_tracer::shapes::{
+    csg, cube, group, intersection, intersection_allowed, intersections, sphere, Csg, CsgOperation,
+    Group, Intersections, Shape,
+};
+use ray_tracer::Float;
+
+mod common;
+
+use common::{address, find, parse_number, parse_transform, parse_triple};
+
+/// intersections refer to their object by name
+/// so the world does not borrow from itself
+type IntersectionRecord = (Float, String);
+
+#[derive(Debug, WorldInit)]
+struct CsgWorld {
+    shapes: HashMap<String, Box<dyn Shape>>,
+    groups: HashMap<String, Group>,
+    addresses: HashMap<String, usize>,
+    csg: Option<Csg>,
+    ray: Option<Ray>,
+    xs: Vec<IntersectionRecord>,
+    result: Vec<IntersectionRecord>,
+    allowed: Option<bool>,
+    normal: Option<Vector>,
+    bounds: Option<BoundingBox>,
+}
+
+fn parse_operation(name: &str) -> CsgOperation {
+    match name {
+        "union" => CsgOperation::Union,
+        "intersection" => CsgOperation::Intersection,
+        "difference" => CsgOperation::Difference,
+        _ => panic!("Unknown CSG operation: {}", name),
+    }
+}
+
+impl CsgWorld {
+    fn csg(&self) -> &Csg {
+        self.csg
+            .as_ref()
+            .unwrap_or_else(|| panic!("CSG shape not created"))
+    }
+
+    fn take_shape(&mut self, name: &str) -> Box<dyn Shape> {
+        match self.groups.remove(name) {
+            Some(g) => Box::new(g),
+            None => self
+                .shapes
+                .remove(name)
+                .unwrap_or_else(|| panic!("Shape {} not created", name)),
+        }
+    }
+
+    fn shape(&self, name: &str) -> &dyn Shape {
+        find(self.csg(), self.addresses[name])
+            .unwrap_or_else(|| panic!("Shape {} is not part of c", name))
+    }
+
+    fn name(&self, object: &dyn Shape) -> String {
+        self.addresses
+            .iter()
+            .find(|(_, &target)| target == address(object))
+            .map(|(name, _)| name.clone())
+            .unwrap_or_else(|| panic!("Intersection with unknown object"))
+    }
+
+    fn describe(&self, xs: &Intersections) -> Vec<IntersectionRecord> {
+        xs.iter().map(|i| (i.t, self.name(i.object))).collect()
+    }
+}
+
+#[async_trait(?Send)]
+impl World for CsgWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            shapes: HashMap::new(),
+            groups: HashMap::new(),
+            addresses: HashMap::new(),
+            csg: Option::None,
+            ray: Option::None,
+            xs: vec![],
+            result: vec![],
+            allowed: Option::None,
+            normal: Option::None,
+            bounds: Option::None,
+        })
+    }
+}
+
+#[given(regex = r"^(\w+) ← (sphere|cube)\(\)$")]
+fn create_shape(world: &mut CsgWorld, name: String, kind: String) {
+    let shape: Box<dyn Shape> = match kind.as_str() {
+        "sphere" => Box::new(sphere()),
+        _ => Box::new(cube()),
+    };
+    world
+        .addresses
+        .insert(name.clone(), address(shape.as_ref()));
+    world.shapes.insert(name, shape);
+}
+
+#[given(regex = r"^(\w+) ← group\(\)$")]
+fn create_group(world: &mut CsgWorld, name: String) {
+    world.groups.insert(name, group());
+}
+
+#[given(regex = r"^add_child\((\w+), (\w+)\)$")]
+fn add_child(world: &mut CsgWorld, parent: String, child: String) {
+    let child = world.take_shape(&child);
+    world
+        .groups
+        .get_mut(&parent)
+        .unwrap_or_else(|| panic!("Group {} not created", parent))
+        .add_child(child);
+}
+
+#[given(regex = r"^set_transform\((s\d), (.+)\)$")]
+fn set_shape_transform(world: &mut CsgWorld, name: String, expression: String) {
+    world
+        .shapes
+        .get_mut(&name)
+        .unwrap_or_else(|| panic!("Shape {} not created", name))
+        .set_transform(parse_transform(&expression));
+}
+
+#[given(regex = r#"^c ← csg\("(\w+)", (\w+), (\w+)\)$"#)]
+#[when(regex = r#"^c ← csg\("(\w+)", (\w+), (\w+)\)$"#)]
+fn create_csg(world: &mut CsgWorld, operation: String, left: String, right: String) {
+    // groups only get a stable address once they are boxed
+    let [left, right] = [left, right].map(|name| {
+        let shape = world.take_shape(&name);
+        world.addresses.insert(name, address(shape.as_ref()));
+        shape
+    });
+    world.csg = Some(csg(parse_operation(&operation), left, right));
+}
+
+#[given(regex = r#"^c ← csg\("(\w+)", sphere\(\), cube\(\)\)$"#)]
+fn create_csg_of_primitives(world: &mut CsgWorld, operation: String) {
+    world.csg = Some(csg(
+        parse_operation(&operation),
+        Box::new(sphere()),
+        Box::new(cube()),
+    ));
+}
+
+#[given(regex = r"^xs ← intersections\((.+)\)$")]
+fn create_intersections(world: &mut CsgWorld, list: String) {
+    world.xs = list
+        .split(", ")
+        .map(|record| {
+            let (t, name) = record
+                .split_once(':')
+                .unwrap_or_else(|| panic!("Invalid intersection: {}", record));
+            (Float::from(parse_number(t)), name.to_string())
+        })
+        .collect();
+}
+
+#[given(regex = r"^r ← ray\(point\(([^)]*)\), vector\(([^)]*)\)\)$")]
+fn create_ray(world: &mut CsgWorld, origin: String, direction: String) {
+    let (x, y, z) = parse_triple(&origin);
+    let (dx, dy, dz) = parse_triple(&direction);
+    world.ray = Some(ray(point(x, y, z), vector(dx, dy, dz)));
+}
+
+#[when(
+    regex = r#"^result ← intersection_allowed\("(\w+)", (true|false), (true|false), (true|false)\)$"#
+)]
+fn when_rule_evaluated(
+    world: &mut CsgWorld,
+    operation: String,
+    left_hit: bool,
+    inside_left: bool,
+    inside_right: bool,
+) {
+    world.allowed = Some(intersection_allowed(
+        parse_operation(&operation),
+        left_hit,
+        inside_left,
+        inside_right,
+    ));
+}
+
+#[when(regex = r"^result ← filter_intersections\(c, xs\)$")]
+fn when_intersections_filtered(world: &mut CsgWorld) {
+    let c = world.csg();
+    let xs = intersections(
+        world
+            .xs
+            .iter()
+            .map(|(t, name)| intersection(*t, world.shape(name)))
+            .collect(),
+    );
+    world.result = world.describe(&c.filter_intersections(xs));
+}
+
+#[when(regex = r"^xs ← local_intersect\(c, r\)$")]
+fn when_csg_intersected(world: &mut CsgWorld) {
+    let r = world.ray.unwrap_or_else(|| panic!("Ray not created"));
+    let xs = world.csg().local_intersect(&r);
+    world.result = if xs.is_empty() {
+        vec![]
+    } else {
+        world.describe(&xs)
+    };
+}
+
+#[when(regex = r"^set_transform\(c, (.+)\)$")]
+fn when_csg_transformed(world: &mut CsgWorld, expression: String) {
+    world
+        .csg
+        .as_mut()
+        .unwrap_or_else(|| panic!("CSG shape not created"))
+        .set_transform(parse_transform(&expression));
+}
+
+#[when(regex = r"^n ← normal_at\((\w+), point\(([^)]*)\)\)$")]
+fn when_normal_computed(world: &mut CsgWorld, name: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.normal = Some(world.shape(&name).normal_at(point(x, y, z)));
+}
+
+#[when(regex = r"^box ← bounds_of\(c\)$")]
+fn when_bounds_queried(world: &mut CsgWorld) {
+    world.bounds = Some(world.csg().bounds());
+}
+
+#[then(regex = r#"^c.operation = "(\w+)"$"#)]
+fn assert_operation(world: &mut CsgWorld, operation: String) {
+    assert_eq!(world.csg().operation, parse_operation(&operation));
+}
+
+#[then(regex = r"^c.(left|right) = (\w+)$")]
+fn assert_child(world: &mut CsgWorld, side: String, name: String) {
+    let c = world.csg();
+    let child = match side.as_str() {
+        "left" => c.left(),
+        _ => c.right(),
+    };
+    assert_eq!(address(child), world.addresses[&name]);
+}
+
+#[then(regex = r"^c (includes|does not include) (\w+)$")]
+fn assert_includes(world: &mut CsgWorld, relation: String, name: String) {
+    let included = match world.addresses.get(&name) {
+        Some(&target) => find(world.csg(), target).is_some_and(|shape| world.csg().includes(shape)),
+        None => false,
+    };
+    assert_eq!(included, relation == "includes");
+}
+
+#[then(regex = r"^result = (true|false)$")]
+fn assert_rule(world: &mut CsgWorld, allowed: bool) {
+    assert_eq!(world.allowed, Some(allowed));
+}
+
+#[then(regex = r"^(?:result|xs).count = (\d+)$")]
+fn assert_result_count(world: &mut CsgWorld, count: usize) {
+    assert_eq!(world.result.len(), count);
+}
+
+#[then(regex = r"^result\[(\d+)\] = xs\[(\d+)\]$")]
+fn assert_result_record(world: &mut CsgWorld, index: usize, record: usize) {
+    assert_eq!(world.result[index], world.xs[record]);
+}
+
+#[then(regex = r"^xs is empty$")]
+fn assert_no_intersections(world: &mut CsgWorld) {
+    assert!(world.result.is_empty());
+}
+
+#[then(regex = r"^xs\[(\d+)\].t = (.+)$")]
+fn assert_intersection_distance(world: &mut CsgWorld, index: usize, t: String) {
+    assert_eq!(world.result[index].0, parse_number(&t));
+}
+
+#[then(regex = r"^xs\[(\d+)\].object = (\w+)$")]
+fn assert_intersection_object(world: &mut CsgWorld, index: usize, name: String) {
+    assert_eq!(world.result[index].1, name);
+}
+
+#[then(regex = r"^n = vector\(([^)]*)\)$")]
+fn assert_normal(world: &mut CsgWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.normal, Some(vector(x, y, z)));
+}
+
+#[then(regex = r"^box.(min|max) = point\(([^)]*)\)$")]
+fn assert_bounds(world: &mut CsgWorld, corner: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    let bounds = world.bounds.unwrap_or_else(|| panic!("Bounds not queried"));
+    let actual = match corner.as_str() {
+        "min" => bounds.min,
+        _ => bounds.max,
+    };
+    assert_eq!(actual, point(x, y, z));
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/csg.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        CsgWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<CsgWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/csg.feature"),
+    );
+}
@@ -0,0 +1,136 @@
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{point, ray, vector, Point, Ray, Vector};
+use ray_tracer::shapes::{cube, Cube, Shape};
+use ray_tracer::Float;
+
+mod common;
+
+use common::{parse_number, parse_transform, parse_triple};
+
+#[derive(Debug, WorldInit)]
+struct CubeWorld {
+    cube: Option<Cube>,
+    ray: Option<Ray>,
+    point: Option<Point>,
+    intersections: Vec<Float>,
+    normal: Option<Vector>,
+}
+
+impl CubeWorld {
+    fn cube(&self) -> &Cube {
+        self.cube
+            .as_ref()
+            .unwrap_or_else(|| panic!("Cube not created"))
+    }
+
+    fn cube_mut(&mut self) -> &mut Cube {
+        self.cube
+            .as_mut()
+            .unwrap_or_else(|| panic!("Cube not created"))
+    }
+}
+
+#[async_trait(?Send)]
+impl World for CubeWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            cube: Option::None,
+            ray: Option::None,
+            point: Option::None,
+            intersections: vec![],
+            normal: Option::None,
+        })
+    }
+}
+
+#[given(regex = r"^c ← cube\(\)$")]
+fn create_cube(world: &mut CubeWorld) {
+    world.cube = Some(cube());
+}
+
+#[given(regex = r"^c.transform ← (.+)$")]
+fn set_cube_transform(world: &mut CubeWorld, expression: String) {
+    world.cube_mut().set_transform(parse_transform(&expression));
+}
+
+#[given(regex = r"^r ← ray\(point\(([^)]*)\), vector\(([^)]*)\)\)$")]
+fn create_ray(world: &mut CubeWorld, origin: String, direction: String) {
+    let (x, y, z) = parse_triple(&origin);
+    let (dx, dy, dz) = parse_triple(&direction);
+    world.ray = Some(ray(point(x, y, z), vector(dx, dy, dz)));
+}
+
+#[given(regex = r"^p ← point\(([^)]*)\)$")]
+fn create_point(world: &mut CubeWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.point = Some(point(x, y, z));
+}
+
+#[when(regex = r"^xs ← local_intersect\(c, r\)$")]
+fn when_cube_intersected(world: &mut CubeWorld) {
+    let r = world.ray.unwrap_or_else(|| panic!("Ray not created"));
+    world.intersections = world
+        .cube()
+        .local_intersect(&r)
+        .iter()
+        .map(|i| i.t)
+        .collect();
+}
+
+#[when(regex = r"^xs ← intersect\(c, r\)$")]
+fn when_transformed_cube_intersected(world: &mut CubeWorld) {
+    let r = world.ray.unwrap_or_else(|| panic!("Ray not created"));
+    world.intersections = world.cube().intersect(&r).iter().map(|i| i.t).collect();
+}
+
+#[when(regex = r"^normal ← local_normal_at\(c, p\)$")]
+fn when_normal_computed(world: &mut CubeWorld) {
+    let p = world.point.unwrap_or_else(|| panic!("Point not created"));
+    world.normal = Some(world.cube().local_normal_at(p));
+}
+
+#[then(regex = r"^xs.count = (\d+)$")]
+fn assert_intersection_count(world: &mut CubeWorld, count: usize) {
+    assert_eq!(world.intersections.len(), count);
+}
+
+#[then(regex = r"^xs\[(\d+)\].t = (.+)$")]
+fn assert_intersection_distance(world: &mut CubeWorld, index: usize, t: String) {
+    assert_eq!(world.intersections[index], parse_number(&t));
+}
+
+#[then(regex = r"^normal = vector\(([^)]*)\)$")]
+fn assert_normal(world: &mut CubeWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.normal, Some(vector(x, y, z)));
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/cubes.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        CubeWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<CubeWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/cubes.feature"),
+    );
+}
@@ -0,0 +1,160 @@
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{normalize, point, ray, vector, Ray, Vector};
+use ray_tracer::shapes::{cylinder, Cylinder, Shape};
+use ray_tracer::Float;
+
+mod common;
+
+use common::{parse_number, parse_transform, parse_triple};
+
+#[derive(Debug, WorldInit)]
+struct CylinderWorld {
+    cylinder: Option<Cylinder>,
+    direction: Option<Vector>,
+    ray: Option<Ray>,
+    intersections: Vec<Float>,
+    normal: Option<Vector>,
+}
+
+impl CylinderWorld {
+    fn cylinder(&mut self) -> &mut Cylinder {
+        self.cylinder
+            .as_mut()
+            .unwrap_or_else(|| panic!("Cylinder not created"))
+    }
+}
+
+#[async_trait(?Send)]
+impl World for CylinderWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            cylinder: Option::None,
+            direction: Option::None,
+            ray: Option::None,
+            intersections: vec![],
+            normal: Option::None,
+        })
+    }
+}
+
+#[given(regex = r"^cyl ← cylinder\(\)$")]
+fn create_cylinder(world: &mut CylinderWorld) {
+    world.cylinder = Some(cylinder());
+}
+
+#[given(regex = r"^cyl.(minimum|maximum) ← (.+)$")]
+fn set_cylinder_bound(world: &mut CylinderWorld, property: String, value: String) {
+    let value = Float::from(parse_number(&value));
+    match property.as_str() {
+        "minimum" => world.cylinder().minimum = value,
+        _ => world.cylinder().maximum = value,
+    }
+}
+
+#[given(regex = r"^cyl.closed ← (true|false)$")]
+fn set_cylinder_closed(world: &mut CylinderWorld, closed: bool) {
+    world.cylinder().closed = closed;
+}
+
+#[given(regex = r"^cyl.transform ← (.+)$")]
+fn set_cylinder_transform(world: &mut CylinderWorld, expression: String) {
+    world.cylinder().set_transform(parse_transform(&expression));
+}
+
+#[given(regex = r"^direction ← normalize\(vector\(([^)]*)\)\)$")]
+fn create_direction(world: &mut CylinderWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.direction = Some(normalize(vector(x, y, z)));
+}
+
+#[given(regex = r"^r ← ray\(point\(([^)]*)\), direction\)$")]
+fn create_ray(world: &mut CylinderWorld, origin: String) {
+    let (x, y, z) = parse_triple(&origin);
+    let direction = world
+        .direction
+        .unwrap_or_else(|| panic!("Direction not created"));
+    world.ray = Some(ray(point(x, y, z), direction));
+}
+
+#[when(regex = r"^xs ← local_intersect\(cyl, r\)$")]
+fn when_cylinder_intersected(world: &mut CylinderWorld) {
+    let r = world.ray.unwrap_or_else(|| panic!("Ray not created"));
+    world.intersections = world
+        .cylinder()
+        .local_intersect(&r)
+        .iter()
+        .map(|i| i.t)
+        .collect();
+}
+
+#[when(regex = r"^xs ← intersect\(cyl, r\)$")]
+fn when_transformed_cylinder_intersected(world: &mut CylinderWorld) {
+    let r = world.ray.unwrap_or_else(|| panic!("Ray not created"));
+    world.intersections = world.cylinder().intersect(&r).iter().map(|i| i.t).collect();
+}
+
+#[when(regex = r"^n ← local_normal_at\(cyl, point\(([^)]*)\)\)$")]
+fn when_normal_computed(world: &mut CylinderWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.normal = Some(world.cylinder().local_normal_at(point(x, y, z)));
+}
+
+#[then(regex = r"^xs.count = (\d+)$")]
+fn assert_intersection_count(world: &mut CylinderWorld, count: usize) {
+    assert_eq!(world.intersections.len(), count);
+}
+
+#[then(regex = r"^xs\[(\d+)\].t = (.+)$")]
+fn assert_intersection_distance(world: &mut CylinderWorld, index: usize, t: String) {
+    assert_eq!(world.intersections[index], parse_number(&t));
+}
+
+#[then(regex = r"^n = vector\(([^)]*)\)$")]
+fn assert_normal(world: &mut CylinderWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.normal, Some(vector(x, y, z)));
+}
+
+#[then(regex = r"^cyl.(minimum|maximum) = (.+)$")]
+fn assert_cylinder_bound(world: &mut CylinderWorld, property: String, value: String) {
+    let actual = match property.as_str() {
+        "minimum" => world.cylinder().minimum,
+        _ => world.cylinder().maximum,
+    };
+    assert_eq!(actual, parse_number(&value));
+}
+
+#[then(regex = r"^cyl.closed = (true|false)$")]
+fn assert_cylinder_closed(world: &mut CylinderWorld, closed: bool) {
+    assert_eq!(world.cylinder().closed, closed);
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/cylinders.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        CylinderWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<CylinderWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/cylinders.feature"),
+    );
+}
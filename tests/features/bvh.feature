@@ -0,0 +1,151 @@
+Feature: Bounding volume hierarchies
+
+Scenario: A group below the threshold is not divided
+  Given s1 ← sphere()
+    And set_transform(s1, translation(-10, 0, 0))
+    And s2 ← sphere()
+    And set_transform(s2, translation(-8, 0, 0))
+    And s3 ← sphere()
+    And set_transform(s3, translation(8, 0, 0))
+    And g ← group()
+    And add_child(g, s1)
+    And add_child(g, s2)
+    And add_child(g, s3)
+  When divide(g, 4)
+  Then g.count = 3
+
+Scenario: The surface area heuristic separates distant clusters
+  Given s1 ← sphere()
+    And set_transform(s1, translation(-10, 0, 0))
+    And s2 ← sphere()
+    And set_transform(s2, translation(-8, 0, 0))
+    And s3 ← sphere()
+    And set_transform(s3, translation(8, 0, 0))
+    And s4 ← sphere()
+    And set_transform(s4, translation(10, 0, 0))
+    And g ← group()
+    And add_child(g, s3)
+    And add_child(g, s1)
+    And add_child(g, s4)
+    And add_child(g, s2)
+  When divide(g, 4)
+  Then g.count = 2
+    And g[0].count = 2
+    And g[1].count = 2
+    And bounds_of(g[0]).min = point(-11, -1, -1)
+    And bounds_of(g[0]).max = point(-7, 1, 1)
+    And bounds_of(g[1]).min = point(7, -1, -1)
+    And bounds_of(g[1]).max = point(11, 1, 1)
+
+Scenario: Dividing recurses into the new subgroups
+  Given s1 ← sphere()
+    And set_transform(s1, translation(-10, 0, 0))
+    And s2 ← sphere()
+    And set_transform(s2, translation(-8, 0, 0))
+    And s3 ← sphere()
+    And set_transform(s3, translation(8, 0, 0))
+    And s4 ← sphere()
+    And set_transform(s4, translation(10, 0, 0))
+    And g ← group()
+    And add_child(g, s1)
+    And add_child(g, s2)
+    And add_child(g, s3)
+    And add_child(g, s4)
+  When divide(g, 1)
+  Then g.count = 2
+    And g[0].count = 2
+    And g[0][0].count = 1
+    And g[0][1].count = 1
+    And bounds_of(g[0][0]).max = point(-9, 1, 1)
+
+Scenario: Unbounded children stay in the divided group
+  Given p ← plane()
+    And s1 ← sphere()
+    And set_transform(s1, translation(-10, 0, 0))
+    And s2 ← sphere()
+    And set_transform(s2, translation(10, 0, 0))
+    And g ← group()
+    And add_child(g, s1)
+    And add_child(g, p)
+    And add_child(g, s2)
+  When divide(g, 3)
+  Then g.count = 3
+    And g[0].count = 0
+    And g[1].count = 1
+    And g[2].count = 1
+
+Scenario: Overlapping children fall back to the midpoint split
+  Given x ← cube()
+    And set_transform(x, scaling(4, 0.1, 0.1))
+    And y ← cube()
+    And set_transform(y, scaling(0.1, 4, 0.1))
+    And z ← cube()
+    And set_transform(z, scaling(0.1, 0.1, 4))
+    And s1 ← sphere()
+    And set_transform(s1, translation(-3, 3, 3) * scaling(0.5, 0.5, 0.5))
+    And s2 ← sphere()
+    And set_transform(s2, translation(3, -3, -3) * scaling(0.5, 0.5, 0.5))
+    And g ← group()
+    And add_child(g, x)
+    And add_child(g, s1)
+    And add_child(g, y)
+    And add_child(g, s2)
+    And add_child(g, z)
+  When divide(g, 5)
+  Then g.count = 5
+    And g[3].count = 1
+    And g[4].count = 1
+    And bounds_of(g[3]).min = point(-3.5, 2.5, 2.5)
+    And bounds_of(g[4]).min = point(2.5, -3.5, -3.5)
+
+Scenario Outline: A divided group is hit in the same places
+  Given s1 ← sphere()
+    And set_transform(s1, translation(-10, 0, 0))
+    And s2 ← sphere()
+    And set_transform(s2, translation(-8, 0, 0))
+    And s3 ← sphere()
+    And set_transform(s3, translation(8, 0, 0))
+    And s4 ← sphere()
+    And set_transform(s4, translation(10, 0, 0))
+    And g ← group()
+    And add_child(g, s1)
+    And add_child(g, s2)
+    And add_child(g, s3)
+    And add_child(g, s4)
+    And r ← ray(<origin>, <direction>)
+  When divide(g, 1)
+    And xs ← intersect(g, r)
+  Then xs[0].t = <t>
+
+  Examples:
+    | origin            | direction        | t |
+    | point(-20, 0, 0)  | vector(1, 0, 0)  | 9 |
+    | point(20, 0, 0)   | vector(-1, 0, 0) | 9 |
+    | point(8, 0, -5)   | vector(0, 0, 1)  | 4 |
+    | point(-8, 10, 0)  | vector(0, -1, 0) | 9 |
+
+Scenario: Children are intersected nearest first
+  Given far ← test_shape()
+    And set_transform(far, translation(0, 0, 10))
+    And near ← sphere()
+    And g ← group()
+    And add_child(g, far)
+    And add_child(g, near)
+    And r ← ray(point(0, 0, -5), vector(0, 0, 1))
+  When xs ← intersect(g, r)
+  Then xs.count = 2
+    And xs[0].t = 4
+    And far.saved_ray is unset
+
+Scenario: A farther child is still intersected when the nearer one is missed
+  Given far ← test_shape()
+    And set_transform(far, translation(0, 0, 10))
+    And near ← test_shape()
+    And g ← group()
+    And add_child(g, far)
+    And add_child(g, near)
+    And r ← ray(point(0, 0, -5), vector(0, 0, 1))
+  When xs ← intersect(g, r)
+  Then xs.count = 0
+    And near.saved_ray is set
+    And far.saved_ray is set
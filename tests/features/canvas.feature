@@ -42,3 +42,15 @@ Scenario: PPM files are terminated by a newline character
   Given c ← canvas(5, 3)
   When ppm ← canvas_to_ppm(c)
   Then ppm ends with a newline character
+
+Scenario: Constructing a PNG image
+  Given c ← canvas(5, 3)
+  And c1 ← color(1.5, 0, 0)
+  And c2 ← color(0, 0.5, 0)
+  When write_pixel(c, 0, 0, c1)
+  And write_pixel(c, 1, 0, c2)
+  And png ← canvas_to_png(c)
+  Then png decodes to 5x3 pixels starting with
+  """
+  255 0 0 0 128 0 0 0 0
+  """
@@ -0,0 +1,76 @@
+Feature: Command-line renderer
+
+Background:
+  Given a scene file "tiny.yml" containing:
+    """
+    - add: camera
+      width: 4
+      height: 3
+      field-of-view: 1.0471975512
+      from: [ 0, 0, -5 ]
+      to: [ 0, 0, 0 ]
+      up: [ 0, 1, 0 ]
+    - add: light
+      at: [ -10, 10, -10 ]
+      intensity: [ 1, 1, 1 ]
+    - add: sphere
+    """
+
+Scenario: Rendering a scene to a PPM file
+  When I run "ray-tracer render tiny.yml -o image.ppm"
+  Then the command succeeds
+    And the output mentions "rendered 4x3 pixels"
+    And the output mentions "12 camera rays"
+    And "image.ppm" starts with "P3\n4 3\n255"
+
+Scenario: The image is written next to the scene by default
+  When I run "ray-tracer render tiny.yml"
+  Then the command succeeds
+    And "tiny.ppm" starts with "P3\n4 3\n255"
+
+Scenario: The encoder is picked from the file extension
+  When I run "ray-tracer render tiny.yml -o image.png"
+  Then the command succeeds
+    And "image.png" starts with "\x89PNG"
+
+Scenario: Overriding the size, samples and threads of the scene
+  When I run "ray-tracer render tiny.yml -o image.ppm --width 8 --samples 2 --threads=2"
+  Then the command succeeds
+    And the output mentions "rendered 8x6 pixels with 2 samples per pixel"
+    And the output mentions "96 camera rays"
+    And "image.ppm" starts with "P3\n8 6\n255"
+
+Scenario: Unsupported output formats are rejected before rendering
+  When I run "ray-tracer render tiny.yml -o image.jpg"
+  Then the command fails with exit code 1
+    And the error mentions "unsupported output format"
+    And "image.jpg" does not exist
+
+Scenario: Errors in the scene file are reported
+  Given a scene file "broken.yml" containing:
+    """
+    - add: blob
+    """
+  When I run "ray-tracer render broken.yml"
+  Then the command fails with exit code 1
+    And the error mentions "broken.yml: entry 1 (add: blob): unknown shape `blob`"
+
+Scenario Outline: Invalid arguments print the usage
+  When I run "<command>"
+  Then the command fails with exit code 2
+    And the error mentions "<error>"
+    And the error mentions "Usage: ray-tracer render"
+
+  Examples:
+    | command                                  | error                                         |
+    | ray-tracer                               | missing command                               |
+    | ray-tracer draw tiny.yml                 | unknown command `draw`                        |
+    | ray-tracer render                        | missing scene file                            |
+    | ray-tracer render tiny.yml --samples 0   | `--samples` must be a positive integer, got `0` |
+    | ray-tracer render tiny.yml --width       | `--width` needs a value                       |
+    | ray-tracer render tiny.yml --fast        | unknown option `--fast`                       |
+
+Scenario: Printing the usage
+  When I run "ray-tracer --help"
+  Then the command succeeds
+    And the output mentions "Usage: ray-tracer render <scene.yml> [options]"
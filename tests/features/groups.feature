@@ -0,0 +1,117 @@
+Feature: Groups
+
+Scenario: Creating a new group
+  Given g ← group()
+  Then g.transform = identity_matrix
+    And g is empty
+
+Scenario: A shape starts without a parent transform
+  Given s ← sphere()
+  Then s.parent_transform = identity_matrix
+
+Scenario: Adding a child to a group
+  Given g ← group()
+    And s ← sphere()
+  When add_child(g, s)
+  Then g is not empty
+    And g includes s
+
+Scenario: Intersecting a ray with an empty group
+  Given g ← group()
+    And r ← ray(point(0, 0, 0), vector(0, 0, 1))
+  When xs ← local_intersect(g, r)
+  Then xs is empty
+
+Scenario: Intersecting a ray with a nonempty group
+  Given g ← group()
+    And s1 ← sphere()
+    And s2 ← sphere()
+    And set_transform(s2, translation(0, 0, -3))
+    And s3 ← sphere()
+    And set_transform(s3, translation(5, 0, 0))
+    And add_child(g, s1)
+    And add_child(g, s2)
+    And add_child(g, s3)
+  When r ← ray(point(0, 0, -5), vector(0, 0, 1))
+    And xs ← local_intersect(g, r)
+  # s1 starts beyond the hit on s2 so it is never intersected
+  Then xs.count = 2
+    And xs[0].object = s2
+    And xs[1].object = s2
+
+Scenario: Intersections behind the ray origin are kept
+  Given g ← group()
+    And s1 ← sphere()
+    And s2 ← sphere()
+    And set_transform(s2, translation(0, 0, -3))
+    And s3 ← sphere()
+    And set_transform(s3, translation(5, 0, 0))
+    And add_child(g, s1)
+    And add_child(g, s2)
+    And add_child(g, s3)
+  When r ← ray(point(0, 0, 0), vector(0, 0, 1))
+    And xs ← local_intersect(g, r)
+  Then xs.count = 4
+    And xs[0].object = s2
+    And xs[1].object = s2
+    And xs[2].object = s1
+    And xs[3].object = s1
+
+Scenario: Intersecting a transformed group
+  Given g ← group()
+    And set_transform(g, scaling(2, 2, 2))
+    And s ← sphere()
+    And set_transform(s, translation(5, 0, 0))
+    And add_child(g, s)
+  When r ← ray(point(10, 0, -10), vector(0, 0, 1))
+    And xs ← intersect(g, r)
+  Then xs.count = 2
+
+Scenario: Converting a point from world to object space
+  Given g1 ← group()
+    And set_transform(g1, rotation_y(π/2))
+    And g2 ← group()
+    And set_transform(g2, scaling(2, 2, 2))
+    And add_child(g1, g2)
+    And s ← sphere()
+    And set_transform(s, translation(5, 0, 0))
+    And add_child(g2, s)
+  When p ← world_to_object(s, point(-2, 0, -10))
+  Then p = point(0, 0, -1)
+
+Scenario: Converting a normal from object to world space
+  Given g1 ← group()
+    And set_transform(g1, rotation_y(π/2))
+    And g2 ← group()
+    And set_transform(g2, scaling(1, 2, 3))
+    And add_child(g1, g2)
+    And s ← sphere()
+    And set_transform(s, translation(5, 0, 0))
+    And add_child(g2, s)
+  When n ← normal_to_world(s, vector(√3/3, √3/3, √3/3))
+  Then n = vector(2/7, 3/7, -6/7)
+
+Scenario: Finding the normal on a child object
+  Given g1 ← group()
+    And set_transform(g1, rotation_y(π/2))
+    And g2 ← group()
+    And set_transform(g2, scaling(1, 2, 3))
+    And add_child(g1, g2)
+    And s ← sphere()
+    And set_transform(s, translation(5, 0, 0))
+    And add_child(g2, s)
+  When n ← normal_at(s, point(1.7321, 1.1547, -5.5774))
+  # the book rounds to 4 places which is coarser than EPSILON
+  Then n = vector(0.28570, 0.42854, -0.85716)
+
+Scenario: Transforming a group after adding children
+  Given g1 ← group()
+    And g2 ← group()
+    And add_child(g1, g2)
+    And s ← sphere()
+    And set_transform(s, translation(5, 0, 0))
+    And add_child(g2, s)
+  When set_transform(g2, scaling(2, 2, 2))
+    And set_transform(g1, rotation_y(π/2))
+    And p ← world_to_object(s, point(-2, 0, -10))
+  Then p = point(0, 0, -1)
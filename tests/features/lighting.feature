@@ -0,0 +1,75 @@
+Feature: Materials and Lighting
+
+Background:
+  Given m ← material()
+    And position ← point(0, 0, 0)
+
+Scenario: A point light has a position and intensity
+  Given intensity ← color(1, 1, 1)
+    And light_position ← point(0, 0, 0)
+  When light ← point_light(light_position, intensity)
+  Then light.position = light_position
+    And light.intensity = intensity
+
+Scenario: The default material
+  Then m.color = color(1, 1, 1)
+    And m.ambient = 0.1
+    And m.diffuse = 0.9
+    And m.specular = 0.9
+    And m.shininess = 200.0
+    And m.reflective = 0.0
+    And m.transparency = 0.0
+    And m.refractive_index = 1.0
+
+Scenario: A shape has a default material
+  Given s ← sphere()
+  Then s.material = material()
+
+Scenario: A shape may be assigned a material
+  Given s ← sphere()
+    And m.ambient ← 1
+  When s.material ← m
+  Then s.material = m
+
+Scenario: Lighting with the eye between the light and the surface
+  Given eyev ← vector(0, 0, -1)
+    And normalv ← vector(0, 0, -1)
+    And light ← point_light(point(0, 0, -10), color(1, 1, 1))
+  When result ← lighting(m, light, position, eyev, normalv)
+  Then result = color(1.9, 1.9, 1.9)
+
+Scenario: Lighting with the eye between light and surface, eye offset 45°
+  Given eyev ← vector(0, √2/2, -√2/2)
+    And normalv ← vector(0, 0, -1)
+    And light ← point_light(point(0, 0, -10), color(1, 1, 1))
+  When result ← lighting(m, light, position, eyev, normalv)
+  Then result = color(1.0, 1.0, 1.0)
+
+Scenario: Lighting with eye opposite surface, light offset 45°
+  Given eyev ← vector(0, 0, -1)
+    And normalv ← vector(0, 0, -1)
+    And light ← point_light(point(0, 10, -10), color(1, 1, 1))
+  When result ← lighting(m, light, position, eyev, normalv)
+  Then result = color(0.7364, 0.7364, 0.7364)
+
+Scenario: Lighting with eye in the path of the reflection vector
+  Given eyev ← vector(0, -√2/2, -√2/2)
+    And normalv ← vector(0, 0, -1)
+    And light ← point_light(point(0, 10, -10), color(1, 1, 1))
+  When result ← lighting(m, light, position, eyev, normalv)
+  Then result = color(1.6364, 1.6364, 1.6364)
+
+Scenario: Lighting with the light behind the surface
+  Given eyev ← vector(0, 0, -1)
+    And normalv ← vector(0, 0, -1)
+    And light ← point_light(point(0, 0, 10), color(1, 1, 1))
+  When result ← lighting(m, light, position, eyev, normalv)
+  Then result = color(0.1, 0.1, 0.1)
+
+Scenario: Lighting with the surface in shadow
+  Given eyev ← vector(0, 0, -1)
+    And normalv ← vector(0, 0, -1)
+    And light ← point_light(point(0, 0, -10), color(1, 1, 1))
+    And in_shadow ← true
+  When result ← lighting(m, light, position, eyev, normalv, in_shadow)
+  Then result = color(0.1, 0.1, 0.1)
@@ -0,0 +1,247 @@
+Feature: OBJ files
+
+Scenario: Ignoring unrecognized lines
+  Given gibberish ← a file containing:
+    """
+    There was a young lady named Bright
+    who traveled much faster than light.
+    She set out one day
+    in a relative way,
+    and came back the previous night.
+    """
+  When parser ← parse_obj_file(gibberish)
+  Then parser should have ignored 5 lines
+
+Scenario: Ignored lines are reported with their line numbers
+  Given file ← a file containing:
+    """
+    # comments and blank lines are not reported
+
+    v 1 2 3
+    mtllib scene.mtl
+    usemtl shiny
+    """
+  When parser ← parse_obj_file(file)
+  Then parser should have ignored 2 lines
+    And parser ignored line 4: "mtllib scene.mtl"
+    And parser ignored line 5: "usemtl shiny"
+
+Scenario: Vertex records
+  Given file ← a file containing:
+    """
+    v -1 1 0
+    v -1.0000 0.5000 0.0000
+    v 1 0 0
+    v 1 1 0
+    """
+  When parser ← parse_obj_file(file)
+  Then parser.vertices[1] = point(-1, 1, 0)
+    And parser.vertices[2] = point(-1, 0.5, 0)
+    And parser.vertices[3] = point(1, 0, 0)
+    And parser.vertices[4] = point(1, 1, 0)
+
+Scenario: Parsing triangle faces
+  Given file ← a file containing:
+    """
+    v -1 1 0
+    v -1 0 0
+    v 1 0 0
+    v 1 1 0
+
+    f 1 2 3
+    f 1 3 4
+    """
+  When parser ← parse_obj_file(file)
+    And g ← parser.default_group
+  Then g.count = 2
+    And g[0].p1 = parser.vertices[1]
+    And g[0].p2 = parser.vertices[2]
+    And g[0].p3 = parser.vertices[3]
+    And g[1].p1 = parser.vertices[1]
+    And g[1].p2 = parser.vertices[3]
+    And g[1].p3 = parser.vertices[4]
+
+Scenario: Triangulating polygons
+  Given file ← a file containing:
+    """
+    v -1 1 0
+    v -1 0 0
+    v 1 0 0
+    v 1 1 0
+    v 0 2 0
+
+    f 1 2 3 4 5
+    """
+  When parser ← parse_obj_file(file)
+    And g ← parser.default_group
+  Then g.count = 3
+    And g[0].p1 = parser.vertices[1]
+    And g[0].p2 = parser.vertices[2]
+    And g[0].p3 = parser.vertices[3]
+    And g[1].p1 = parser.vertices[1]
+    And g[1].p2 = parser.vertices[3]
+    And g[1].p3 = parser.vertices[4]
+    And g[2].p1 = parser.vertices[1]
+    And g[2].p2 = parser.vertices[4]
+    And g[2].p3 = parser.vertices[5]
+
+Scenario: Triangles in groups
+  Given file ← a file containing:
+    """
+    v -1 1 0
+    v -1 0 0
+    v 1 0 0
+    v 1 1 0
+
+    g FirstGroup
+    f 1 2 3
+    o SecondGroup
+    f 1 3 4
+    """
+  When parser ← parse_obj_file(file)
+    And g ← "FirstGroup" from parser
+  Then g.count = 1
+    And g[0].p1 = parser.vertices[1]
+    And g[0].p2 = parser.vertices[2]
+    And g[0].p3 = parser.vertices[3]
+  When g ← "SecondGroup" from parser
+  Then g.count = 1
+    And g[0].p1 = parser.vertices[1]
+    And g[0].p2 = parser.vertices[3]
+    And g[0].p3 = parser.vertices[4]
+
+Scenario: Converting an OBJ file to a group
+  Given file ← a file containing:
+    """
+    v -1 1 0
+    v -1 0 0
+    v 1 0 0
+    v 1 1 0
+
+    f 1 2 4
+    g FirstGroup
+    f 1 2 3
+    g SecondGroup
+    f 1 3 4
+    g FirstGroup
+    f 2 3 4
+    """
+  When parser ← parse_obj_file(file)
+    And shape ← obj_to_group(parser)
+  Then shape.count = 3
+    And shape[0].count = 0
+    And shape[1].count = 2
+    And shape[2].count = 1
+
+Scenario: Named groups without faces are left out of the group
+  Given file ← a file containing:
+    """
+    o Cube
+    g Cube_Material
+    v -1 1 0
+    v -1 0 0
+    v 1 0 0
+    f 1 2 3
+    """
+  When parser ← parse_obj_file(file)
+    And shape ← obj_to_group(parser)
+  Then shape.count = 1
+    And shape[0].count = 1
+
+Scenario: Vertex normal records
+  Given file ← a file containing:
+    """
+    vn 0 0 1
+    vn 0.707 0 -0.707
+    vn 1 2 3
+    """
+  When parser ← parse_obj_file(file)
+  Then parser.normals[1] = vector(0, 0, 1)
+    And parser.normals[2] = vector(0.707, 0, -0.707)
+    And parser.normals[3] = vector(1, 2, 3)
+
+Scenario: Faces with normals
+  Given file ← a file containing:
+    """
+    v 0 1 0
+    v -1 0 0
+    v 1 0 0
+
+    vt 0 0
+    vt 0.5 1
+    vt 1 0
+
+    vn -1 0 0
+    vn 1 0 0
+    vn 0 1 0
+
+    f 1//3 2//1 3//2
+    f 1/1/3 2/2/1 3/3/2
+    """
+  When parser ← parse_obj_file(file)
+    And g ← parser.default_group
+  Then g.count = 2
+    And g[0] is smooth
+    And g[0].p1 = parser.vertices[1]
+    And g[0].p2 = parser.vertices[2]
+    And g[0].p3 = parser.vertices[3]
+    And g[0].n1 = parser.normals[3]
+    And g[0].n2 = parser.normals[1]
+    And g[0].n3 = parser.normals[2]
+    And g[1] is smooth
+    And g[1].n1 = parser.normals[3]
+    And g[1].n2 = parser.normals[1]
+    And g[1].n3 = parser.normals[2]
+
+Scenario: Faces with texture vertices but no normals are flat
+  Given file ← a file containing:
+    """
+    v 0 1 0
+    v -1 0 0
+    v 1 0 0
+    vt 0 0
+    vt 0.5
+    f 1/1 2/2 3/1
+    """
+  When parser ← parse_obj_file(file)
+    And g ← parser.default_group
+  Then g.count = 1
+    And g[0] is flat
+
+Scenario: Negative indices count back from the latest vertex
+  Given file ← a file containing:
+    """
+    v 0 1 0
+    v -1 0 0
+    v 1 0 0
+    f -3 -2 -1
+    """
+  When parser ← parse_obj_file(file)
+    And g ← parser.default_group
+  Then g[0].p1 = parser.vertices[1]
+    And g[0].p2 = parser.vertices[2]
+    And g[0].p3 = parser.vertices[3]
+
+Scenario Outline: Malformed statements are reported with their line number
+  Given file ← a file containing:
+    """
+    v 0 1 0
+    v -1 0 0
+    v 1 0 0
+    vn 0 0 1
+    <statement>
+    """
+  When parser ← parse_obj_file(file)
+  Then parsing fails with "<message>"
+
+  Examples:
+    | statement    | message                             |
+    | f 1 2 4      | line 5: invalid index `4`           |
+    | f 1 2 0      | line 5: invalid index `0`           |
+    | f 1 -4 3     | line 5: invalid index `-4`          |
+    | f 1 two 3    | line 5: invalid index `two`         |
+    | f 1//1 2//2 3 | line 5: invalid index `2`          |
+    | f 1/1 2 3    | line 5: invalid index `1`           |
+    | f 1 2        | line 5: not enough values for `f`   |
+    | v 1 2        | line 5: not enough values for `v`   |
+    | vn 1 x 3     | line 5: invalid number `x`          |
@@ -0,0 +1,213 @@
+Feature: Scene files
+
+Scenario: Adding a camera
+  Given file ← a scene containing:
+    """
+    - add: camera
+      width: 100
+      height: 50
+      field-of-view: 0.785
+      from: [ 0, 1.5, -5 ]
+      to: [ 0, 1, 0 ]
+      up: [ 0, 1, 0 ]
+    """
+  When scene ← parse_scene(file)
+  Then scene.camera.hsize = 100
+    And scene.camera.vsize = 50
+    And scene.camera.field_of_view = 0.785
+    And scene.camera.transform = view_transform(point(0, 1.5, -5), point(0, 1, 0), vector(0, 1, 0))
+    And scene.world contains 0 objects
+
+Scenario: Adding a light
+  Given file ← a scene containing:
+    """
+    - add: camera
+      width: 10
+      height: 10
+      field-of-view: 1
+      from: [ 0, 0, -5 ]
+      to: [ 0, 0, 0 ]
+      up: [ 0, 1, 0 ]
+    - add: light
+      at: [ -10, 10, -10 ]
+      intensity: [ 1, 0.5, 1 ]
+    """
+  When scene ← parse_scene(file)
+  Then scene.world contains 1 light
+    And scene.world.lights[0].position = point(-10, 10, -10)
+    And scene.world.lights[0].intensity = color(1, 0.5, 1)
+
+Scenario: Adding a shape with a material and a transform
+  Given file ← a scene with a camera and:
+    """
+    - add: sphere
+      material:
+        color: [ 0.1, 1, 0.5 ]
+        diffuse: 0.7
+        refractive-index: 1.5
+      transform:
+        - [ translate, 1, 0, 0 ]
+        - [ scale, 2, 2, 2 ]
+    """
+  When scene ← parse_scene(file)
+  Then scene.world contains 1 object
+    And scene.world.objects[0].transform = scaling(2, 2, 2) * translation(1, 0, 0)
+    And scene.world.objects[0].material.color = color(0.1, 1, 0.5)
+    And scene.world.objects[0].material.diffuse = 0.7
+    And scene.world.objects[0].material.refractive_index = 1.5
+    And scene.world.objects[0].material.specular = 0.9
+
+Scenario: Extending a defined material
+  Given file ← a scene with a camera and:
+    """
+    - define: white-material
+      value:
+        color: [ 1, 1, 1 ]
+        diffuse: 0.7
+        reflective: 0.1
+    - define: blue-material
+      extend: white-material
+      value:
+        color: [ 0.537, 0.831, 0.914 ]
+    - add: cube
+      material: blue-material
+    """
+  When scene ← parse_scene(file)
+  Then scene.world.objects[0].material.color = color(0.537, 0.831, 0.914)
+    And scene.world.objects[0].material.diffuse = 0.7
+    And scene.world.objects[0].material.reflective = 0.1
+
+Scenario: Transforms can refer to defined transforms
+  Given file ← a scene with a camera and:
+    """
+    - define: standard-transform
+      value:
+        - [ translate, 1, -1, 1 ]
+        - [ scale, 0.5, 0.5, 0.5 ]
+    - define: large-object
+      value:
+        - standard-transform
+        - [ scale, 3.5, 3.5, 3.5 ]
+    - add: plane
+      transform:
+        - large-object
+        - [ rotate-x, 1.5707963267948966 ]
+    """
+  When scene ← parse_scene(file)
+  Then scene.world.objects[0].transform = rotation_x(1.5707963267948966) * scaling(3.5, 3.5, 3.5) * scaling(0.5, 0.5, 0.5) * translation(1, -1, 1)
+
+Scenario: Adding a defined shape
+  Given file ← a scene with a camera and:
+    """
+    - define: pillar
+      value:
+        add: cylinder
+        min: 0
+        max: 2
+        closed: true
+        material:
+          color: [ 1, 0, 0 ]
+    - add: pillar
+      transform:
+        - [ translate, 3, 0, 0 ]
+    """
+  When scene ← parse_scene(file)
+  Then scene.world.objects[0].bounds.min = point(-1, 0, -1)
+    And scene.world.objects[0].bounds.max = point(1, 2, 1)
+    # both caps are hit, an open cylinder would be missed entirely
+    And local_intersect(scene.world.objects[0], ray(point(0, 5, 0), vector(0, -1, 0))).count = 2
+    And scene.world.objects[0].material.color = color(1, 0, 0)
+    And scene.world.objects[0].transform = translation(3, 0, 0)
+
+Scenario: Adding a group
+  Given file ← a scene with a camera and:
+    """
+    - add: group
+      transform:
+        - [ translate, 0, 0, 5 ]
+      children:
+        - add: sphere
+        - add: cone
+          min: -1
+          max: 0
+    """
+  When scene ← parse_scene(file)
+  Then scene.world.objects[0] has 2 children
+    And scene.world.objects[0].children[1] world transform = translation(0, 0, 5)
+
+Scenario: Adding a CSG shape
+  Given file ← a scene with a camera and:
+    """
+    - add: csg
+      operation: difference
+      left:
+        add: cube
+      right:
+        add: sphere
+        transform:
+          - [ scale, 1.3, 1.3, 1.3 ]
+    """
+  When scene ← parse_scene(file)
+  Then scene.world.objects[0] has 2 children
+    And scene.world.objects[0].children[1].transform = scaling(1.3, 1.3, 1.3)
+
+Scenario: Materials can have patterns
+  Given file ← a scene with a camera and:
+    """
+    - add: sphere
+      material:
+        pattern:
+          type: stripes
+          colors:
+            - [ 1, 1, 1 ]
+            - [ 0, 0, 0 ]
+          transform:
+            - [ scale, 0.5, 0.5, 0.5 ]
+    """
+  When scene ← parse_scene(file)
+  Then scene.world.objects[0].material.pattern.transform = scaling(0.5, 0.5, 0.5)
+
+Scenario Outline: Errors point to the offending entry
+  Given file ← a scene with a camera and:
+    """
+    - add: light
+      at: [ 0, 0, 0 ]
+      intensity: [ 1, 1, 1 ]
+    - <entry>
+    """
+  When scene ← parse_scene(file)
+  Then parsing fails with "<error>"
+
+  Examples:
+    | entry                                          | error                                                 |
+    | add: blob                                      | entry 3 (add: blob): unknown shape `blob`             |
+    | { add: sphere, colour: [ 1, 0, 0 ] }           | entry 3 (add: sphere): unknown key `colour`           |
+    | { add: cube, material: chrome }                | entry 3 (add: cube): unknown definition `chrome`      |
+    | { add: cube, material: { shine: 1 } }          | entry 3 (add: cube): unknown material key `shine`     |
+    | { add: plane, transform: [ [ spin, 1 ] ] }     | entry 3 (add: plane): unknown transform `spin`        |
+    | { add: plane, transform: [ [ scale, 1, 2 ] ] } | entry 3 (add: plane): `scale` takes 3 values          |
+    | { add: plane, transform: [ [ scale, 0, 1, 1 ] ] } | entry 3 (add: plane): invalid transform: matrix is not invertible |
+    | { add: camera, width: 10, height: 10, field-of-view: 1, from: [ 0, 0, -5 ], to: [ 0, 0, -5 ], up: [ 0, 1, 0 ] } | entry 3 (add: camera): invalid transform: matrix is not invertible |
+    | { add: group, transform: [ [ scale, 1e-60, 1e-60, 1e-60 ] ], children: [ { add: sphere, transform: [ [ scale, 1e-60, 1e-60, 1e-60 ] ] } ] } | entry 3 (add: group): invalid transform: matrix is not invertible once combined with its groups |
+    | { add: light, at: [ 0, 0 ], intensity: [ 1, 1, 1 ] } | entry 3 (add: light): `at` must be a list of three numbers |
+    | { add: cylinder, closed: yes }                 | entry 3 (add: cylinder): `closed` must be true or false |
+    | { define: metal }                              | entry 3 (define: metal): missing `value`              |
+    | { define: spin, value: [ spin ] }              | entry 3 (define: spin): definition `spin` refers to itself |
+    | { define: spin, value: [ [ rotate-y, 1 ], turn ] } | entry 3 (define: spin): unknown definition `turn` |
+    | sphere                                         | entry 3: `entry` must be a mapping                    |
+
+Scenario: A scene needs a camera
+  Given file ← a scene containing:
+    """
+    - add: sphere
+    """
+  When scene ← parse_scene(file)
+  Then parsing fails with "scene has no camera"
+
+Scenario: Invalid YAML is rejected
+  Given file ← a scene containing:
+    """
+    - add: [ sphere
+    """
+  When scene ← parse_scene(file)
+  Then parsing fails
@@ -112,6 +112,19 @@ Scenario: The cross product of two vectors
   Then cross(a, b) = vector(-1, 2, -1)
   And cross(b, a) = vector(1, -2, 1)
 
+Scenario: Reflecting a vector approaching at 45°
+  Given v ← vector(1, -1, 0)
+  And n ← vector(0, 1, 0)
+  When r ← reflect(v, n)
+  Then r = vector(1, 1, 0)
+
+Scenario: Reflecting a vector off a slanted surface
+  Given v ← vector(0, -1, 0)
+                      # vector(√2/2, √2/2, 0)
+  And n ← vector(0.70711, 0.70711, 0)
+  When r ← reflect(v, n)
+  Then r = vector(1, 0, 0)
+
 Scenario: Colors are (red, green, blue) tuples
   Given c ← color(-0.5, 0.4, 1.7)
   Then c.red = -0.5
@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{identity_matrix, point, ray, vector, Point, Ray, Vector};
+use ray_tracer::shapes::{group, sphere, Group, Shape};
+use ray_tracer::Float;
+
+mod common;
+
+use common::{address, find, parse_transform, parse_triple};
+
+/// Shapes are moved into their groups, so the hierarchy is only assembled once every
+/// `add_child` has been declared and named shapes are found again by their address
+#[derive(Debug, WorldInit)]
+struct GroupWorld {
+    groups: HashMap<String, Group>,
+    shapes: HashMap<String, Box<dyn Shape>>,
+    children: Vec<(String, String)>,
+    roots: HashMap<String, Box<dyn Shape>>,
+    addresses: HashMap<String, usize>,
+    ray: Option<Ray>,
+    intersections: Vec<(Float, usize)>,
+    point: Option<Point>,
+    normal: Option<Vector>,
+}
+
+impl GroupWorld {
+    fn build(&mut self, name: &str) -> Box<dyn Shape> {
+        let shape: Box<dyn Shape> = match self.groups.remove(name) {
+            Some(mut g) => {
+                let children: Vec<String> = self
+                    .children
+                    .iter()
+                    .filter(|(parent, _)| parent == name)
+                    .map(|(_, child)| child.clone())
+                    .collect();
+                for child in children {
+                    g.add_child(self.build(&child));
+                }
+                Box::new(g)
+            }
+            None => self
+                .shapes
+                .remove(name)
+                .unwrap_or_else(|| panic!("Shape {} not created", name)),
+        };
+        self.addresses
+            .insert(name.to_string(), address(shape.as_ref()));
+        shape
+    }
+
+    /// moves every declared shape into its parent group
+    fn assemble(&mut self) {
+        let mut names: Vec<String> = self.groups.keys().cloned().collect();
+        names.extend(self.shapes.keys().cloned());
+        for name in names {
+            if self.children.iter().any(|(_, child)| *child == name) {
+                continue;
+            }
+            let root = self.build(&name);
+            self.roots.insert(name, root);
+        }
+        self.children.clear();
+    }
+
+    fn shape(&mut self, name: &str) -> &dyn Shape {
+        self.assemble();
+        let target = self.addresses[name];
+        self.roots
+            .values()
+            .find_map(|root| find(root.as_ref(), target))
+            .unwrap_or_else(|| panic!("Shape {} not created", name))
+    }
+
+    fn ray(&self) -> Ray {
+        self.ray.unwrap_or_else(|| panic!("Ray not created"))
+    }
+}
+
+#[async_trait(?Send)]
+impl World for GroupWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            groups: HashMap::new(),
+            shapes: HashMap::new(),
+            children: vec![],
+            roots: HashMap::new(),
+            addresses: HashMap::new(),
+            ray: Option::None,
+            intersections: vec![],
+            point: Option::None,
+            normal: Option::None,
+        })
+    }
+}
+
+#[given(regex = r"^(\w+) ← group\(\)$")]
+fn create_group(world: &mut GroupWorld, name: String) {
+    world.groups.insert(name, group());
+}
+
+#[given(regex = r"^(\w+) ← sphere\(\)$")]
+fn create_sphere(world: &mut GroupWorld, name: String) {
+    world.shapes.insert(name, Box::new(sphere()));
+}
+
+#[given(regex = r"^set_transform\((\w+), (.+)\)$")]
+#[when(regex = r"^set_transform\((\w+), (.+)\)$")]
+fn set_shape_transform(world: &mut GroupWorld, name: String, expression: String) {
+    let transform = parse_transform(&expression);
+    if let Some(g) = world.groups.get_mut(&name) {
+        g.set_transform(transform);
+    } else if let Some(shape) = world.shapes.get_mut(&name) {
+        shape.set_transform(transform);
+    } else {
+        world
+            .roots
+            .get_mut(&name)
+            .unwrap_or_else(|| panic!("Shape {} is not a root shape", name))
+            .set_transform(transform);
+    }
+}
+
+#[given(regex = r"^add_child\((\w+), (\w+)\)$")]
+#[when(regex = r"^add_child\((\w+), (\w+)\)$")]
+fn add_child(world: &mut GroupWorld, parent: String, child: String) {
+    world.children.push((parent, child));
+}
+
+#[given(regex = r"^r ← ray\(point\(([^)]*)\), vector\(([^)]*)\)\)$")]
+#[when(regex = r"^r ← ray\(point\(([^)]*)\), vector\(([^)]*)\)\)$")]
+fn create_ray(world: &mut GroupWorld, origin: String, direction: String) {
+    let (x, y, z) = parse_triple(&origin);
+    let (dx, dy, dz) = parse_triple(&direction);
+    world.ray = Some(ray(point(x, y, z), vector(dx, dy, dz)));
+}
+
+#[when(regex = r"^xs ← (local_intersect|intersect)\((\w+), r\)$")]
+fn when_group_intersected(world: &mut GroupWorld, function: String, name: String) {
+    let r = world.ray();
+    let shape = world.shape(&name);
+    let xs = match function.as_str() {
+        "local_intersect" => shape.local_intersect(&r),
+        _ => shape.intersect(&r),
+    };
+    world.intersections = xs.iter().map(|i| (i.t, address(i.object))).collect();
+}
+
+#[when(regex = r"^p ← world_to_object\((\w+), point\(([^)]*)\)\)$")]
+fn when_point_converted(world: &mut GroupWorld, name: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.point = Some(world.shape(&name).world_to_object(point(x, y, z)));
+}
+
+#[when(regex = r"^n ← normal_to_world\((\w+), vector\(([^)]*)\)\)$")]
+fn when_normal_converted(world: &mut GroupWorld, name: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.normal = Some(world.shape(&name).normal_to_world(vector(x, y, z)));
+}
+
+#[when(regex = r"^n ← normal_at\((\w+), point\(([^)]*)\)\)$")]
+fn when_normal_computed(world: &mut GroupWorld, name: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.normal = Some(world.shape(&name).normal_at(point(x, y, z)));
+}
+
+#[then(regex = r"^(\w+).transform = identity_matrix$")]
+fn assert_default_transform(world: &mut GroupWorld, name: String) {
+    assert_eq!(world.shape(&name).transform(), identity_matrix());
+}
+
+#[then(regex = r"^(\w+).parent_transform = identity_matrix$")]
+fn assert_default_parent_transform(world: &mut GroupWorld, name: String) {
+    let parent_transform = world.shape(&name).properties().parent_transform();
+    assert_eq!(parent_transform, identity_matrix());
+}
+
+#[then(regex = r"^(g\d*) is (not )?empty$")]
+fn assert_group_empty(world: &mut GroupWorld, name: String, not: String) {
+    assert_eq!(world.shape(&name).children().is_empty(), not.is_empty());
+}
+
+#[then(regex = r"^(\w+) includes (\w+)$")]
+fn assert_group_includes(world: &mut GroupWorld, name: String, child: String) {
+    world.assemble();
+    let target = world.addresses[&child];
+    let g = world.shape(&name);
+    assert!(g
+        .children()
+        .iter()
+        .any(|shape| address(shape.as_ref()) == target));
+}
+
+#[then(regex = r"^xs is empty$")]
+fn assert_no_intersections(world: &mut GroupWorld) {
+    assert!(world.intersections.is_empty());
+}
+
+#[then(regex = r"^xs.count = (\d+)$")]
+fn assert_intersection_count(world: &mut GroupWorld, count: usize) {
+    assert_eq!(world.intersections.len(), count);
+}
+
+#[then(regex = r"^xs\[(\d+)\].object = (\w+)$")]
+fn assert_intersection_object(world: &mut GroupWorld, index: usize, name: String) {
+    world.assemble();
+    assert_eq!(world.intersections[index].1, world.addresses[&name]);
+}
+
+#[then(regex = r"^p = point\(([^)]*)\)$")]
+fn assert_point(world: &mut GroupWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.point, Some(point(x, y, z)));
+}
+
+#[then(regex = r"^n = vector\(([^)]*)\)$")]
+fn assert_normal(world: &mut GroupWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.normal, Some(vector(x, y, z)));
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/groups.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        GroupWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<GroupWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/groups.feature"),
+    );
+}
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{point, ray, vector, Ray};
+use ray_tracer::shapes::{intersection, intersections, sphere, Intersections, Shape, Sphere};
+use ray_tracer::Float;
+
+mod common;
+
+use common::{parse_number, parse_triple};
+
+/// intersections refer to their object by name
+/// so the world does not borrow from itself
+type IntersectionRecord = (Float, String);
+
+#[derive(Debug, WorldInit)]
+struct IntersectionWorld {
+    ray: Option<Ray>,
+    spheres: HashMap<String, Sphere>,
+    intersections: HashMap<String, IntersectionRecord>,
+    xs: Vec<IntersectionRecord>,
+    hit: Option<IntersectionRecord>,
+}
+
+impl IntersectionWorld {
+    fn sphere(&self, name: &str) -> &Sphere {
+        self.spheres
+            .get(name)
+            .unwrap_or_else(|| panic!("Sphere {} not created", name))
+    }
+
+    fn record(&self, name: &str) -> IntersectionRecord {
+        self.intersections
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| panic!("Intersection {} not created", name))
+    }
+
+    fn object_name(&self, object: &dyn Shape) -> String {
+        self.spheres
+            .iter()
+            .find(|(_, sphere)| std::ptr::addr_eq(*sphere, object))
+            .map(|(name, _)| name.clone())
+            .unwrap_or_else(|| panic!("Intersection with unknown object"))
+    }
+
+    fn build(&self, records: &[IntersectionRecord]) -> Intersections<'_> {
+        intersections(
+            records
+                .iter()
+                .map(|(t, name)| intersection(*t, self.sphere(name)))
+                .collect(),
+        )
+    }
+
+    fn describe(&self, xs: &Intersections) -> Vec<IntersectionRecord> {
+        xs.iter()
+            .map(|i| (i.t, self.object_name(i.object)))
+            .collect()
+    }
+}
+
+#[async_trait(?Send)]
+impl World for IntersectionWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            ray: Option::None,
+            spheres: HashMap::new(),
+            intersections: HashMap::new(),
+            xs: vec![],
+            hit: Option::None,
+        })
+    }
+}
+
+#[given(regex = r"^r ← ray\(point\(([^)]*)\), vector\(([^)]*)\)\)$")]
+fn create_ray(world: &mut IntersectionWorld, origin: String, direction: String) {
+    let (x, y, z) = parse_triple(&origin);
+    let (dx, dy, dz) = parse_triple(&direction);
+    world.ray = Some(ray(point(x, y, z), vector(dx, dy, dz)));
+}
+
+#[given(regex = r"^(\w+) ← sphere\(\)$")]
+fn create_sphere(world: &mut IntersectionWorld, name: String) {
+    world.spheres.insert(name, sphere());
+}
+
+#[given(regex = r"^(\w+) ← intersection\(([^,]+), (\w+)\)$")]
+#[when(regex = r"^(\w+) ← intersection\(([^,]+), (\w+)\)$")]
+fn create_intersection(world: &mut IntersectionWorld, name: String, t: String, object: String) {
+    let record = (Float::from(parse_number(&t)), object);
+    world.intersections.insert(name, record);
+}
+
+#[given(regex = r"^xs ← intersections\(([\w, ]+)\)$")]
+#[when(regex = r"^xs ← intersections\(([\w, ]+)\)$")]
+fn create_intersections(world: &mut IntersectionWorld, names: String) {
+    let records = names
+        .split(", ")
+        .map(|name| world.record(name))
+        .collect::<Vec<IntersectionRecord>>();
+    world.xs = world.describe(&world.build(&records));
+}
+
+#[when(regex = r"^xs ← intersect\((\w+), r\)$")]
+fn when_ray_intersects(world: &mut IntersectionWorld, object: String) {
+    let r = world.ray.unwrap_or_else(|| panic!("Ray not created"));
+    world.xs = world.describe(&world.sphere(&object).intersect(&r));
+}
+
+#[when(regex = r"^i ← hit\(xs\)$")]
+fn when_hit_computed(world: &mut IntersectionWorld) {
+    let xs = world.build(&world.xs);
+    world.hit = xs.hit().map(|i| (i.t, world.object_name(i.object)));
+}
+
+#[then(regex = r"^i.t = (.+)$")]
+fn assert_intersection_distance(world: &mut IntersectionWorld, t: String) {
+    assert_eq!(world.record("i").0, parse_number(&t));
+}
+
+#[then(regex = r"^i.object = (\w+)$")]
+fn assert_intersection_object(world: &mut IntersectionWorld, object: String) {
+    assert_eq!(world.record("i").1, object);
+}
+
+#[then(regex = r"^xs.count = (\d+)$")]
+fn assert_intersection_count(world: &mut IntersectionWorld, count: usize) {
+    assert_eq!(world.xs.len(), count);
+}
+
+#[then(regex = r"^xs\[(\d+)\].t = (.+)$")]
+fn assert_indexed_distance(world: &mut IntersectionWorld, index: usize, t: String) {
+    assert_eq!(world.xs[index].0, parse_number(&t));
+}
+
+#[then(regex = r"^xs\[(\d+)\].object = (\w+)$")]
+fn assert_indexed_object(world: &mut IntersectionWorld, index: usize, object: String) {
+    assert_eq!(world.xs[index].1, object);
+}
+
+#[then(regex = r"^xs\[(\d+)\] = (\w+)$")]
+fn assert_indexed_intersection(world: &mut IntersectionWorld, index: usize, expected: String) {
+    assert_eq!(world.xs[index], world.record(&expected));
+}
+
+#[then(regex = r"^i = (\w+)$")]
+fn assert_hit(world: &mut IntersectionWorld, expected: String) {
+    assert_eq!(world.hit, Some(world.record(&expected)));
+}
+
+#[then(regex = r"^i is nothing$")]
+fn assert_no_hit(world: &mut IntersectionWorld) {
+    assert_eq!(world.hit, None);
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/intersections.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        IntersectionWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<IntersectionWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/intersections.feature"),
+    );
+}
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{point, vector, Point, Vector};
+use ray_tracer::graphics::{color, lighting, material, point_light, Color, Material, PointLight};
+use ray_tracer::shapes::{sphere, Shape, Sphere};
+use ray_tracer::Float;
+
+mod common;
+
+use common::{parse_number, parse_triple};
+
+#[derive(Debug, WorldInit)]
+struct LightingWorld {
+    material: Material,
+    points: HashMap<String, Point>,
+    vectors: HashMap<String, Vector>,
+    colors: HashMap<String, Color>,
+    light: Option<PointLight>,
+    in_shadow: bool,
+    sphere: Option<Sphere>,
+    result: Option<Color>,
+}
+
+impl LightingWorld {
+    fn light(&self) -> PointLight {
+        self.light.unwrap_or_else(|| panic!("Light not created"))
+    }
+
+    fn sphere(&mut self) -> &mut Sphere {
+        self.sphere
+            .as_mut()
+            .unwrap_or_else(|| panic!("Sphere not created"))
+    }
+}
+
+#[async_trait(?Send)]
+impl World for LightingWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            material: material(),
+            points: HashMap::new(),
+            vectors: HashMap::new(),
+            colors: HashMap::new(),
+            light: Option::None,
+            in_shadow: false,
+            sphere: Option::None,
+            result: Option::None,
+        })
+    }
+}
+
+#[given(regex = r"^m ← material\(\)$")]
+fn create_material(world: &mut LightingWorld) {
+    world.material = material();
+}
+
+#[given(regex = r"^m.ambient ← (.+)$")]
+fn set_material_ambient(world: &mut LightingWorld, value: String) {
+    world.material.ambient = Float::from(parse_number(&value));
+}
+
+#[given(regex = r"^(\w+) ← (point|vector|color)\(([^)]*)\)$")]
+fn create_tuple(world: &mut LightingWorld, name: String, kind: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    match kind.as_str() {
+        "point" => {
+            world.points.insert(name, point(x, y, z));
+        }
+        "vector" => {
+            world.vectors.insert(name, vector(x, y, z));
+        }
+        _ => {
+            world.colors.insert(name, color(x, y, z));
+        }
+    }
+}
+
+#[given(regex = r"^light ← point_light\(point\(([^)]*)\), color\(([^)]*)\)\)$")]
+fn create_light(world: &mut LightingWorld, position: String, intensity: String) {
+    let (x, y, z) = parse_triple(&position);
+    let (red, green, blue) = parse_triple(&intensity);
+    world.light = Some(point_light(point(x, y, z), color(red, green, blue)));
+}
+
+#[given(regex = r"^in_shadow ← (true|false)$")]
+fn set_in_shadow(world: &mut LightingWorld, in_shadow: bool) {
+    world.in_shadow = in_shadow;
+}
+
+#[given(regex = r"^s ← sphere\(\)$")]
+fn create_sphere(world: &mut LightingWorld) {
+    world.sphere = Some(sphere());
+}
+
+#[when(regex = r"^light ← point_light\((\w+), (\w+)\)$")]
+fn when_light_created(world: &mut LightingWorld, position: String, intensity: String) {
+    world.light = Some(point_light(
+        world.points[&position],
+        world.colors[&intensity],
+    ));
+}
+
+#[when(regex = r"^s.material ← m$")]
+fn when_material_assigned(world: &mut LightingWorld) {
+    let m = world.material.clone();
+    world.sphere().set_material(m);
+}
+
+#[when(regex = r"^result ← lighting\(m, light, (\w+), (\w+), (\w+)(?:, in_shadow)?\)$")]
+fn when_lighting_computed(
+    world: &mut LightingWorld,
+    position: String,
+    eyev: String,
+    normalv: String,
+) {
+    world.result = Some(lighting(
+        &world.material,
+        &sphere(),
+        &world.light(),
+        world.points[&position],
+        world.vectors[&eyev],
+        world.vectors[&normalv],
+        world.in_shadow,
+    ));
+}
+
+#[then(regex = r"^light.position = (\w+)$")]
+fn assert_light_position(world: &mut LightingWorld, position: String) {
+    assert_eq!(world.light().position, world.points[&position]);
+}
+
+#[then(regex = r"^light.intensity = (\w+)$")]
+fn assert_light_intensity(world: &mut LightingWorld, intensity: String) {
+    assert_eq!(world.light().intensity, world.colors[&intensity]);
+}
+
+#[then(regex = r"^m.color = color\(([^)]*)\)$")]
+fn assert_material_color(world: &mut LightingWorld, values: String) {
+    let (red, green, blue) = parse_triple(&values);
+    assert_eq!(world.material.color, color(red, green, blue));
+}
+
+#[then(
+    regex = r"^m.(ambient|diffuse|specular|shininess|reflective|transparency|refractive_index) = (.+)$"
+)]
+fn assert_material_property(world: &mut LightingWorld, property: String, value: String) {
+    let m = &world.material;
+    let actual = match property.as_str() {
+        "ambient" => m.ambient,
+        "diffuse" => m.diffuse,
+        "specular" => m.specular,
+        "shininess" => m.shininess,
+        "reflective" => m.reflective,
+        "transparency" => m.transparency,
+        _ => m.refractive_index,
+    };
+    assert_eq!(actual, parse_number(&value));
+}
+
+#[then(regex = r"^s.material = material\(\)$")]
+fn assert_default_shape_material(world: &mut LightingWorld) {
+    assert_eq!(*world.sphere().material(), material());
+}
+
+#[then(regex = r"^s.material = m$")]
+fn assert_shape_material(world: &mut LightingWorld) {
+    let m = world.material.clone();
+    assert_eq!(*world.sphere().material(), m);
+}
+
+#[then(regex = r"^result = color\(([^)]*)\)$")]
+fn assert_lighting_result(world: &mut LightingWorld, values: String) {
+    let (red, green, blue) = parse_triple(&values);
+    assert_eq!(world.result, Some(color(red, green, blue)));
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/lighting.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        LightingWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<LightingWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/lighting.feature"),
+    );
+}
@@ -0,0 +1,355 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::gherkin::Step;
+use cucumber::{given, then, World, WorldInit};
+
+use ray_tracer::geometry::{identity_matrix, matrix, point, vector, Matrix, Point, Vector};
+use ray_tracer::Float;
+
+#[derive(Clone, Copy, Debug)]
+enum AnyMatrix {
+    TwoByTwo(Matrix<2>),
+    ThreeByThree(Matrix<3>),
+    FourByFour(Matrix<4>),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum TupleType {
+    PointTuple(Point),
+    VectorTuple(Vector),
+}
+
+fn parse_table(step: &Step) -> Vec<Vec<f64>> {
+    let table = step
+        .table
+        .as_ref()
+        .unwrap_or_else(|| panic!("Missing matrix table"));
+
+    table
+        .rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| {
+                    cell.trim()
+                        .parse::<f64>()
+                        .unwrap_or_else(|_| panic!("Invalid matrix value: {}", cell))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn table_to_matrix<const N: usize>(table: &[Vec<f64>]) -> Matrix<N> {
+    let mut rows = [[0.0; N]; N];
+    assert_eq!(table.len(), N, "Expected {} rows", N);
+    for (row, values) in table.iter().enumerate() {
+        assert_eq!(values.len(), N, "Expected {} columns", N);
+        rows[row].copy_from_slice(values);
+    }
+    matrix(rows)
+}
+
+fn table_to_any_matrix(size: usize, step: &Step) -> AnyMatrix {
+    let table = parse_table(step);
+    match size {
+        2 => AnyMatrix::TwoByTwo(table_to_matrix(&table)),
+        3 => AnyMatrix::ThreeByThree(table_to_matrix(&table)),
+        4 => AnyMatrix::FourByFour(table_to_matrix(&table)),
+        _ => panic!("Unsupported matrix size: {}", size),
+    }
+}
+
+fn parse_fraction(value: &str) -> f64 {
+    match value.split_once('/') {
+        Some((numerator, denominator)) => {
+            numerator.parse::<f64>().unwrap() / denominator.parse::<f64>().unwrap()
+        }
+        None => value.parse::<f64>().unwrap(),
+    }
+}
+
+#[derive(Debug, WorldInit)]
+struct MatrixWorld {
+    matrices: HashMap<String, AnyMatrix>,
+    tuples: HashMap<String, TupleType>,
+}
+
+impl MatrixWorld {
+    fn matrix(&self, name: &str) -> AnyMatrix {
+        *self
+            .matrices
+            .get(name)
+            .unwrap_or_else(|| panic!("Matrix {} not created", name))
+    }
+
+    fn matrix4(&self, name: &str) -> Matrix<4> {
+        match name {
+            "identity_matrix" => identity_matrix(),
+            _ => match self.matrix(name) {
+                AnyMatrix::FourByFour(m) => m,
+                _ => panic!("Matrix {} is not a 4x4 matrix", name),
+            },
+        }
+    }
+
+    fn tuple(&self, name: &str) -> TupleType {
+        *self
+            .tuples
+            .get(name)
+            .unwrap_or_else(|| panic!("Tuple {} not created", name))
+    }
+}
+
+#[async_trait(?Send)]
+impl World for MatrixWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            matrices: HashMap::new(),
+            tuples: HashMap::new(),
+        })
+    }
+}
+
+#[given(regex = r"^the following (?:(\d)x\d )?matrix (\w+):$")]
+fn create_matrix_from_table(world: &mut MatrixWorld, size: String, name: String, step: &Step) {
+    let size = size.parse::<usize>().unwrap_or(4);
+    world.matrices.insert(name, table_to_any_matrix(size, step));
+}
+
+#[given(regex = r"^(\w+) ← tuple\((-?\d+.?\d*), (-?\d+.?\d*), (-?\d+.?\d*), (-?\d+.?\d*)\)$")]
+fn create_tuple(world: &mut MatrixWorld, name: String, x: f64, y: f64, z: f64, w: f64) {
+    let tuple = if w == 0.0 {
+        TupleType::VectorTuple(vector(x, y, z))
+    } else if w == 1.0 {
+        TupleType::PointTuple(point(x, y, z))
+    } else {
+        panic!("Unexpected tuple type: {}", w);
+    };
+    world.tuples.insert(name, tuple);
+}
+
+#[given(regex = r"^(\w+) ← transpose\((\w+)\)$")]
+fn create_transposed_matrix(world: &mut MatrixWorld, name: String, source: String) {
+    let transposed = world.matrix4(&source).transpose();
+    world
+        .matrices
+        .insert(name, AnyMatrix::FourByFour(transposed));
+}
+
+#[given(regex = r"^(\w+) ← submatrix\((\w+), (\d), (\d)\)$")]
+fn create_submatrix(world: &mut MatrixWorld, name: String, source: String, r: usize, c: usize) {
+    let submatrix = match world.matrix(&source) {
+        AnyMatrix::ThreeByThree(m) => AnyMatrix::TwoByTwo(m.submatrix(r, c)),
+        AnyMatrix::FourByFour(m) => AnyMatrix::ThreeByThree(m.submatrix(r, c)),
+        AnyMatrix::TwoByTwo(_) => panic!("2x2 matrices have no submatrix"),
+    };
+    world.matrices.insert(name, submatrix);
+}
+
+#[given(regex = r"^(\w+) ← inverse\((\w+)\)$")]
+fn create_inverse_matrix(world: &mut MatrixWorld, name: String, source: String) {
+    let inverse = world
+        .matrix4(&source)
+        .inverse()
+        .unwrap_or_else(|error| panic!("Failed to invert {}: {}", source, error));
+    world.matrices.insert(name, AnyMatrix::FourByFour(inverse));
+}
+
+#[given(regex = r"^(\w+) ← (\w+) \* (\w+)$")]
+fn create_matrix_product(world: &mut MatrixWorld, name: String, lhs: String, rhs: String) {
+    let product = world.matrix4(&lhs) * world.matrix4(&rhs);
+    world.matrices.insert(name, AnyMatrix::FourByFour(product));
+}
+
+#[then(regex = r"^(\w+)\[(\d),(\d)\] = (-?\d+\.?\d*(?:/\d+)?)$")]
+fn assert_matrix_element(world: &mut MatrixWorld, name: String, r: usize, c: usize, value: String) {
+    let element = match world.matrix(&name) {
+        AnyMatrix::TwoByTwo(m) => m[(r, c)],
+        AnyMatrix::ThreeByThree(m) => m[(r, c)],
+        AnyMatrix::FourByFour(m) => m[(r, c)],
+    };
+    assert_eq!(element, parse_fraction(&value));
+}
+
+#[then(regex = r"^(\w+) (=|!=) (\w+)$")]
+fn assert_matrix_equality(world: &mut MatrixWorld, lhs: String, operator: String, rhs: String) {
+    let lhs = world.matrix4(&lhs);
+    let rhs = world.matrix4(&rhs);
+    match operator.as_str() {
+        "=" => assert_eq!(lhs, rhs),
+        "!=" => assert_ne!(lhs, rhs),
+        _ => panic!("Unknown operator: {}", operator),
+    }
+}
+
+#[then(regex = r"^(\w+) \* (\w+) = (\w+)$")]
+fn assert_matrix_product_equals(
+    world: &mut MatrixWorld,
+    lhs: String,
+    rhs: String,
+    expected: String,
+) {
+    if world.tuples.contains_key(&rhs) {
+        let m = world.matrix4(&lhs);
+        match (world.tuple(&rhs), world.tuple(&expected)) {
+            (TupleType::PointTuple(p), TupleType::PointTuple(e)) => assert_eq!(m * p, e),
+            (TupleType::VectorTuple(v), TupleType::VectorTuple(e)) => assert_eq!(m * v, e),
+            _ => panic!("Tuple types do not match"),
+        }
+    } else {
+        assert_eq!(
+            world.matrix4(&lhs) * world.matrix4(&rhs),
+            world.matrix4(&expected)
+        );
+    }
+}
+
+#[then(regex = r"^(\w+) \* inverse\((\w+)\) = (\w+)$")]
+fn assert_product_with_inverse(
+    world: &mut MatrixWorld,
+    lhs: String,
+    rhs: String,
+    expected: String,
+) {
+    let inverse = world.matrix4(&rhs).inverse().unwrap();
+    assert_eq!(world.matrix4(&lhs) * inverse, world.matrix4(&expected));
+}
+
+#[then(
+    regex = r"^(\w+) \* (\w+) = tuple\((-?\d+.?\d*), (-?\d+.?\d*), (-?\d+.?\d*), (-?\d+.?\d*)\)$"
+)]
+fn assert_matrix_tuple_product(
+    world: &mut MatrixWorld,
+    name: String,
+    tuple: String,
+    expected_x: f64,
+    expected_y: f64,
+    expected_z: f64,
+    expected_w: f64,
+) {
+    let m = world.matrix4(&name);
+    let (x, y, z, w) = match world.tuple(&tuple) {
+        TupleType::PointTuple(p) => {
+            let Point { x, y, z } = m * p;
+            (x, y, z, Float::from(1.0))
+        }
+        TupleType::VectorTuple(v) => {
+            let Vector { x, y, z } = m * v;
+            (x, y, z, Float::from(0.0))
+        }
+    };
+
+    assert_eq!(x, expected_x);
+    assert_eq!(y, expected_y);
+    assert_eq!(z, expected_z);
+    assert_eq!(w, expected_w);
+}
+
+#[then(regex = r"^(.+) is the following (\d)x\d matrix:$")]
+fn assert_matrix_expression(world: &mut MatrixWorld, expression: String, size: usize, step: &Step) {
+    let expected = table_to_any_matrix(size, step);
+
+    let actual = if let Some(operand) = expression
+        .strip_prefix("transpose(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        AnyMatrix::FourByFour(world.matrix4(operand).transpose())
+    } else if let Some(operand) = expression
+        .strip_prefix("inverse(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        AnyMatrix::FourByFour(world.matrix4(operand).inverse().unwrap())
+    } else if let Some(arguments) = expression
+        .strip_prefix("submatrix(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        let arguments = arguments.split(", ").collect::<Vec<&str>>();
+        let (r, c) = (arguments[1].parse().unwrap(), arguments[2].parse().unwrap());
+        match world.matrix(arguments[0]) {
+            AnyMatrix::ThreeByThree(m) => AnyMatrix::TwoByTwo(m.submatrix(r, c)),
+            AnyMatrix::FourByFour(m) => AnyMatrix::ThreeByThree(m.submatrix(r, c)),
+            AnyMatrix::TwoByTwo(_) => panic!("2x2 matrices have no submatrix"),
+        }
+    } else if let Some((lhs, rhs)) = expression.split_once(" * ") {
+        AnyMatrix::FourByFour(world.matrix4(lhs) * world.matrix4(rhs))
+    } else {
+        world.matrix(&expression)
+    };
+
+    match (actual, expected) {
+        (AnyMatrix::TwoByTwo(a), AnyMatrix::TwoByTwo(e)) => assert_eq!(a, e),
+        (AnyMatrix::ThreeByThree(a), AnyMatrix::ThreeByThree(e)) => assert_eq!(a, e),
+        (AnyMatrix::FourByFour(a), AnyMatrix::FourByFour(e)) => assert_eq!(a, e),
+        _ => panic!("Matrix sizes do not match"),
+    }
+}
+
+#[then(regex = r"^(determinant|minor|cofactor)\((\w+)(?:, (\d), (\d))?\) = (-?\d+)$")]
+fn assert_matrix_scalar_operations(
+    world: &mut MatrixWorld,
+    operation: String,
+    name: String,
+    row: String,
+    column: String,
+    expected_value: f64,
+) {
+    let m = world.matrix(&name);
+    let (r, c) = (row.parse().unwrap_or(0), column.parse().unwrap_or(0));
+
+    let result = match (operation.as_str(), m) {
+        ("determinant", AnyMatrix::TwoByTwo(m)) => m.determinant(),
+        ("determinant", AnyMatrix::ThreeByThree(m)) => m.determinant(),
+        ("determinant", AnyMatrix::FourByFour(m)) => m.determinant(),
+        ("minor", AnyMatrix::ThreeByThree(m)) => m.minor(r, c),
+        ("minor", AnyMatrix::FourByFour(m)) => m.minor(r, c),
+        ("cofactor", AnyMatrix::ThreeByThree(m)) => m.cofactor(r, c),
+        ("cofactor", AnyMatrix::FourByFour(m)) => m.cofactor(r, c),
+        _ => panic!("Unsupported operation: {}", operation),
+    };
+
+    assert_eq!(result, expected_value);
+}
+
+#[then(regex = r"^(\w+) is (invertible|not invertible)$")]
+fn assert_matrix_invertibility(world: &mut MatrixWorld, name: String, invertibility: String) {
+    let m = world.matrix4(&name);
+    match invertibility.as_str() {
+        "invertible" => assert!(m.is_invertible()),
+        "not invertible" => assert!(!m.is_invertible()),
+        _ => panic!("Unknown invertibility: {}", invertibility),
+    }
+}
+
+#[then(regex = r"^inverse\((\w+)\) fails$")]
+fn assert_inverse_fails(world: &mut MatrixWorld, name: String) {
+    assert!(world.matrix4(&name).inverse().is_err());
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/matrices.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        MatrixWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<MatrixWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/matrices.feature"),
+    );
+}
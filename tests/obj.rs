@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::gherkin::Step;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{point, vector};
+use ray_tracer::parsers::{obj_to_group, parse_obj_file, ObjError, ObjFile, ObjTriangle};
+use ray_tracer::shapes::{Group, Shape};
+
+mod common;
+
+use common::parse_triple;
+
+#[derive(Debug, WorldInit)]
+struct ObjWorld {
+    files: HashMap<String, String>,
+    parser: Option<Result<ObjFile, ObjError>>,
+    group: Vec<ObjTriangle>,
+    shape: Option<Group>,
+}
+
+impl ObjWorld {
+    fn parser(&self) -> &ObjFile {
+        match &self.parser {
+            Some(Ok(parser)) => parser,
+            Some(Err(error)) => panic!("Failed to parse OBJ file: {}", error),
+            None => panic!("OBJ file not parsed"),
+        }
+    }
+
+    fn shape(&self) -> &Group {
+        self.shape
+            .as_ref()
+            .unwrap_or_else(|| panic!("Group not converted"))
+    }
+}
+
+#[async_trait(?Send)]
+impl World for ObjWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            files: HashMap::new(),
+            parser: Option::None,
+            group: vec![],
+            shape: Option::None,
+        })
+    }
+}
+
+#[given(regex = r"^(\w+) ← a file containing:$")]
+fn create_file(world: &mut ObjWorld, name: String, step: &Step) {
+    let docstring = step
+        .docstring()
+        .unwrap_or_else(|| panic!("Missing docstring"));
+    // drop the newline that follows the opening quotes so line numbers match the file
+    let contents = docstring.strip_prefix('\n').unwrap_or(docstring);
+    world.files.insert(name, contents.to_string());
+}
+
+#[when(regex = r"^parser ← parse_obj_file\((\w+)\)$")]
+fn when_file_parsed(world: &mut ObjWorld, name: String) {
+    world.parser = Some(parse_obj_file(&world.files[&name]));
+}
+
+#[when(regex = r"^g ← parser.default_group$")]
+fn when_default_group_selected(world: &mut ObjWorld) {
+    world.group = world.parser().default_group.clone();
+}
+
+#[when(regex = r#"^g ← "(\w+)" from parser$"#)]
+fn when_named_group_selected(world: &mut ObjWorld, name: String) {
+    world.group = world
+        .parser()
+        .group(&name)
+        .unwrap_or_else(|| panic!("Group {} not parsed", name))
+        .to_vec();
+}
+
+#[when(regex = r"^shape ← obj_to_group\(parser\)$")]
+fn when_converted_to_group(world: &mut ObjWorld) {
+    let parser = world.parser().clone();
+    world.shape = Some(obj_to_group(parser));
+}
+
+#[then(regex = r"^parser should have ignored (\d+) lines$")]
+fn assert_ignored_count(world: &mut ObjWorld, count: usize) {
+    assert_eq!(world.parser().ignored.len(), count);
+}
+
+#[then(regex = r#"^parser ignored line (\d+): "(.+)"$"#)]
+fn assert_ignored_line(world: &mut ObjWorld, line: usize, text: String) {
+    assert!(world
+        .parser()
+        .ignored
+        .iter()
+        .any(|ignored| ignored.line == line && ignored.text == text));
+}
+
+#[then(regex = r"^parser.vertices\[(\d+)\] = point\(([^)]*)\)$")]
+fn assert_vertex(world: &mut ObjWorld, index: usize, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.parser().vertex(index), Some(point(x, y, z)));
+}
+
+#[then(regex = r"^parser.normals\[(\d+)\] = vector\(([^)]*)\)$")]
+fn assert_normal(world: &mut ObjWorld, index: usize, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.parser().normal(index), Some(vector(x, y, z)));
+}
+
+#[then(regex = r"^g.count = (\d+)$")]
+fn assert_triangle_count(world: &mut ObjWorld, count: usize) {
+    assert_eq!(world.group.len(), count);
+}
+
+#[then(regex = r"^g\[(\d+)\] is (smooth|flat)$")]
+fn assert_triangle_kind(world: &mut ObjWorld, index: usize, kind: String) {
+    let smooth = matches!(world.group[index], ObjTriangle::Smooth(_));
+    assert_eq!(smooth, kind == "smooth");
+}
+
+#[then(regex = r"^g\[(\d+)\].(p1|p2|p3) = parser.vertices\[(\d+)\]$")]
+fn assert_triangle_corner(world: &mut ObjWorld, index: usize, corner: String, vertex: usize) {
+    let (p1, p2, p3) = match &world.group[index] {
+        ObjTriangle::Flat(t) => (t.p1(), t.p2(), t.p3()),
+        ObjTriangle::Smooth(t) => (t.p1(), t.p2(), t.p3()),
+    };
+    let actual = match corner.as_str() {
+        "p1" => p1,
+        "p2" => p2,
+        _ => p3,
+    };
+    assert_eq!(Some(actual), world.parser().vertex(vertex));
+}
+
+#[then(regex = r"^g\[(\d+)\].(n1|n2|n3) = parser.normals\[(\d+)\]$")]
+fn assert_triangle_normal(world: &mut ObjWorld, index: usize, corner: String, normal: usize) {
+    let t = match &world.group[index] {
+        ObjTriangle::Smooth(t) => t,
+        ObjTriangle::Flat(_) => panic!("Triangle {} is not smooth", index),
+    };
+    let actual = match corner.as_str() {
+        "n1" => t.n1(),
+        "n2" => t.n2(),
+        _ => t.n3(),
+    };
+    assert_eq!(Some(actual), world.parser().normal(normal));
+}
+
+#[then(regex = r"^shape.count = (\d+)$")]
+fn assert_group_count(world: &mut ObjWorld, count: usize) {
+    assert_eq!(world.shape().children().len(), count);
+}
+
+#[then(regex = r"^shape\[(\d+)\].count = (\d+)$")]
+fn assert_subgroup_count(world: &mut ObjWorld, index: usize, count: usize) {
+    assert_eq!(world.shape().children()[index].children().len(), count);
+}
+
+#[then(regex = r#"^parsing fails with "(.+)"$"#)]
+fn assert_parse_error(world: &mut ObjWorld, message: String) {
+    match &world.parser {
+        Some(Err(error)) => assert_eq!(error.to_string(), message.trim()),
+        _ => panic!("OBJ file was parsed"),
+    }
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/obj.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        ObjWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<ObjWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/obj.feature"),
+    );
+}
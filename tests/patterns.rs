@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{identity_matrix, point, vector, Vector};
+use ray_tracer::graphics::{color, lighting, material, point_light, Color, Material, PointLight};
+use ray_tracer::patterns::{
+    checkers_pattern, gradient_pattern, ring_pattern, stripe_pattern, Pattern, Stripe,
+};
+use ray_tracer::shapes::{sphere, Shape, Sphere};
+use ray_tracer::Float;
+
+mod common;
+
+use common::{parse_number, parse_transform, parse_triple, test_pattern};
+
+#[derive(Debug, WorldInit)]
+struct PatternWorld {
+    colors: HashMap<String, Color>,
+    vectors: HashMap<String, Vector>,
+    pattern: Option<Box<dyn Pattern>>,
+    stripe: Option<Stripe>,
+    shape: Option<Sphere>,
+    material: Material,
+    light: Option<PointLight>,
+}
+
+impl PatternWorld {
+    fn color(&self, expression: &str) -> Color {
+        match expression.strip_prefix("color(") {
+            Some(values) => {
+                let (red, green, blue) = parse_triple(values.trim_end_matches(')'));
+                color(red, green, blue)
+            }
+            None => *self
+                .colors
+                .get(expression)
+                .unwrap_or_else(|| panic!("Color {} not created", expression)),
+        }
+    }
+
+    fn pattern(&mut self) -> &mut Box<dyn Pattern> {
+        self.pattern
+            .as_mut()
+            .unwrap_or_else(|| panic!("Pattern not created"))
+    }
+
+    fn shape(&mut self) -> &mut Sphere {
+        self.shape
+            .as_mut()
+            .unwrap_or_else(|| panic!("Shape not created"))
+    }
+}
+
+#[async_trait(?Send)]
+impl World for PatternWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            colors: HashMap::new(),
+            vectors: HashMap::new(),
+            pattern: Option::None,
+            stripe: Option::None,
+            shape: Option::None,
+            material: material(),
+            light: Option::None,
+        })
+    }
+}
+
+#[given(regex = r"^(\w+) ← color\(([^)]*)\)$")]
+fn create_color(world: &mut PatternWorld, name: String, values: String) {
+    let (red, green, blue) = parse_triple(&values);
+    world.colors.insert(name, color(red, green, blue));
+}
+
+#[given(regex = r"^(\w+) ← vector\(([^)]*)\)$")]
+fn create_vector(world: &mut PatternWorld, name: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.vectors.insert(name, vector(x, y, z));
+}
+
+#[given(regex = r"^pattern ← (stripe|gradient|ring|checkers)_pattern\((\w+), (\w+)\)$")]
+fn create_pattern(world: &mut PatternWorld, kind: String, a: String, b: String) {
+    let (a, b) = (world.color(&a), world.color(&b));
+    let pattern: Box<dyn Pattern> = match kind.as_str() {
+        "stripe" => {
+            world.stripe = Some(stripe_pattern(a, b));
+            Box::new(stripe_pattern(a, b))
+        }
+        "gradient" => Box::new(gradient_pattern(a, b)),
+        "ring" => Box::new(ring_pattern(a, b)),
+        _ => Box::new(checkers_pattern(a, b)),
+    };
+    world.pattern = Some(pattern);
+}
+
+#[given(regex = r"^pattern ← test_pattern\(\)$")]
+fn create_test_pattern(world: &mut PatternWorld) {
+    world.pattern = Some(Box::new(test_pattern()));
+}
+
+#[given(regex = r"^set_pattern_transform\(pattern, (.+)\)$")]
+#[when(regex = r"^set_pattern_transform\(pattern, (.+)\)$")]
+fn set_pattern_transform(world: &mut PatternWorld, expression: String) {
+    world.pattern().set_transform(parse_transform(&expression));
+}
+
+#[given(regex = r"^(\w+) ← sphere\(\)$")]
+fn create_shape(world: &mut PatternWorld, _name: String) {
+    world.shape = Some(sphere());
+}
+
+#[given(regex = r"^set_transform\((\w+), (.+)\)$")]
+fn set_shape_transform(world: &mut PatternWorld, _name: String, expression: String) {
+    world.shape().set_transform(parse_transform(&expression));
+}
+
+#[given(regex = r"^m ← material\(\)$")]
+fn create_material(world: &mut PatternWorld) {
+    world.material = material();
+}
+
+#[given(regex = r"^m.pattern ← stripe_pattern\(color\(([^)]*)\), color\(([^)]*)\)\)$")]
+fn set_material_pattern(world: &mut PatternWorld, a: String, b: String) {
+    let (a, b) = (parse_triple(&a), parse_triple(&b));
+    world.material.pattern = Some(Arc::new(stripe_pattern(
+        color(a.0, a.1, a.2),
+        color(b.0, b.1, b.2),
+    )));
+}
+
+#[given(regex = r"^m.(ambient|diffuse|specular) ← (.+)$")]
+fn set_material_property(world: &mut PatternWorld, property: String, value: String) {
+    let value = Float::from(parse_number(&value));
+    match property.as_str() {
+        "ambient" => world.material.ambient = value,
+        "diffuse" => world.material.diffuse = value,
+        _ => world.material.specular = value,
+    }
+}
+
+#[given(regex = r"^light ← point_light\(point\(([^)]*)\), color\(([^)]*)\)\)$")]
+fn create_light(world: &mut PatternWorld, position: String, intensity: String) {
+    let (x, y, z) = parse_triple(&position);
+    let (red, green, blue) = parse_triple(&intensity);
+    world.light = Some(point_light(point(x, y, z), color(red, green, blue)));
+}
+
+#[when(regex = r"^(\w+) ← lighting\(m, light, point\(([^)]*)\), eyev, normalv, (true|false)\)$")]
+fn when_lighting_computed(world: &mut PatternWorld, name: String, values: String, in_shadow: bool) {
+    let (x, y, z) = parse_triple(&values);
+    let result = lighting(
+        &world.material,
+        &sphere(),
+        &world.light.unwrap_or_else(|| panic!("Light not created")),
+        point(x, y, z),
+        world.vectors["eyev"],
+        world.vectors["normalv"],
+        in_shadow,
+    );
+    world.colors.insert(name, result);
+}
+
+#[when(regex = r"^c ← pattern_at_shape\(pattern, (\w+), point\(([^)]*)\)\)$")]
+fn when_pattern_at_shape(world: &mut PatternWorld, _name: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    let shape = world
+        .shape
+        .as_ref()
+        .unwrap_or_else(|| panic!("Shape not created"));
+    let pattern = world
+        .pattern
+        .as_ref()
+        .unwrap_or_else(|| panic!("Pattern not created"));
+    let result = pattern.pattern_at_shape(shape, point(x, y, z));
+    world.colors.insert("c".to_string(), result);
+}
+
+#[then(regex = r"^pattern.(a|b) = (\w+)$")]
+fn assert_stripe_color(world: &mut PatternWorld, property: String, expected: String) {
+    let stripe = world
+        .stripe
+        .as_ref()
+        .unwrap_or_else(|| panic!("Stripe pattern not created"));
+    let actual = match property.as_str() {
+        "a" => stripe.a,
+        _ => stripe.b,
+    };
+    assert_eq!(actual, world.color(&expected));
+}
+
+#[then(regex = r"^pattern.transform = identity_matrix$")]
+fn assert_default_pattern_transform(world: &mut PatternWorld) {
+    assert_eq!(world.pattern().transform(), identity_matrix());
+}
+
+#[then(regex = r"^pattern.transform = ((?:translation|scaling|rotation_[xyz]|shearing).*)$")]
+fn assert_pattern_transform(world: &mut PatternWorld, expression: String) {
+    assert_eq!(world.pattern().transform(), parse_transform(&expression));
+}
+
+#[then(regex = r"^pattern_at\(pattern, point\(([^)]*)\)\) = (.+)$")]
+fn assert_pattern_at(world: &mut PatternWorld, values: String, expected: String) {
+    let (x, y, z) = parse_triple(&values);
+    let expected = world.color(&expected);
+    assert_eq!(world.pattern().pattern_at(point(x, y, z)), expected);
+}
+
+#[then(regex = r"^(\w+) = (.+)$")]
+fn assert_color(world: &mut PatternWorld, name: String, expected: String) {
+    assert_eq!(world.color(&name), world.color(&expected));
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/patterns.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        PatternWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<PatternWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/patterns.feature"),
+    );
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{point, ray, vector, Ray, Vector};
+use ray_tracer::shapes::{plane, Intersections, Plane, Shape};
+
+mod common;
+
+use common::{parse_number, parse_transform, parse_triple};
+
+#[derive(Debug, WorldInit)]
+struct PlaneWorld {
+    plane: Option<Plane>,
+    ray: Option<Ray>,
+    /// whether `xs` was computed in object space
+    local: bool,
+    normals: HashMap<String, Vector>,
+}
+
+impl PlaneWorld {
+    fn plane(&mut self) -> &mut Plane {
+        self.plane
+            .as_mut()
+            .unwrap_or_else(|| panic!("Plane not created"))
+    }
+
+    /// intersections borrow the plane so they are recomputed for each assertion
+    fn xs(&self) -> Intersections<'_> {
+        let plane = self
+            .plane
+            .as_ref()
+            .unwrap_or_else(|| panic!("Plane not created"));
+        let r = self.ray.unwrap_or_else(|| panic!("Ray not created"));
+        if self.local {
+            plane.local_intersect(&r)
+        } else {
+            plane.intersect(&r)
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl World for PlaneWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            plane: Option::None,
+            ray: Option::None,
+            local: false,
+            normals: HashMap::new(),
+        })
+    }
+}
+
+#[given(regex = r"^p ← plane\(\)$")]
+fn create_plane(world: &mut PlaneWorld) {
+    world.plane = Some(plane());
+}
+
+#[given(regex = r"^set_transform\(p, (.+)\)$")]
+fn set_plane_transform(world: &mut PlaneWorld, expression: String) {
+    world.plane().set_transform(parse_transform(&expression));
+}
+
+#[given(regex = r"^r ← ray\(point\(([^)]*)\), vector\(([^)]*)\)\)$")]
+fn create_ray(world: &mut PlaneWorld, origin: String, direction: String) {
+    let (x, y, z) = parse_triple(&origin);
+    let (dx, dy, dz) = parse_triple(&direction);
+    world.ray = Some(ray(point(x, y, z), vector(dx, dy, dz)));
+}
+
+#[when(regex = r"^(\w+) ← local_normal_at\(p, point\(([^)]*)\)\)$")]
+fn when_local_normal_computed(world: &mut PlaneWorld, name: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    let normal = world.plane().local_normal_at(point(x, y, z));
+    world.normals.insert(name, normal);
+}
+
+#[when(regex = r"^xs ← (local_intersect|intersect)\(p, r\)$")]
+fn when_plane_intersected(world: &mut PlaneWorld, function: String) {
+    world.local = function == "local_intersect";
+}
+
+#[then(regex = r"^(\w+) = vector\(([^)]*)\)$")]
+fn assert_normal(world: &mut PlaneWorld, name: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.normals[&name], vector(x, y, z));
+}
+
+#[then(regex = r"^xs is empty$")]
+fn assert_no_intersections(world: &mut PlaneWorld) {
+    assert!(world.xs().is_empty());
+}
+
+#[then(regex = r"^xs.count = (\d+)$")]
+fn assert_intersection_count(world: &mut PlaneWorld, count: usize) {
+    assert_eq!(world.xs().len(), count);
+}
+
+#[then(regex = r"^xs\[(\d+)\].t = (.+)$")]
+fn assert_intersection_distance(world: &mut PlaneWorld, index: usize, t: String) {
+    assert_eq!(world.xs()[index].t, parse_number(&t));
+}
+
+#[then(regex = r"^xs\[(\d+)\].object = p$")]
+fn assert_intersection_object(world: &mut PlaneWorld, index: usize) {
+    let xs = world.xs();
+    let plane: &dyn Shape = world.plane.as_ref().unwrap();
+    assert!(std::ptr::addr_eq(xs[index].object, plane));
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/planes.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        PlaneWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<PlaneWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/planes.feature"),
+    );
+}
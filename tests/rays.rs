@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{point, ray, scaling, translation, vector, Matrix, Point, Ray, Vector};
+
+mod common;
+
+use common::{parse_number, parse_triple};
+
+#[derive(Debug, WorldInit)]
+struct RayWorld {
+    points: HashMap<String, Point>,
+    vectors: HashMap<String, Vector>,
+    rays: HashMap<String, Ray>,
+    transforms: HashMap<String, Matrix<4>>,
+}
+
+impl RayWorld {
+    fn ray(&self, name: &str) -> Ray {
+        *self
+            .rays
+            .get(name)
+            .unwrap_or_else(|| panic!("Ray {} not created", name))
+    }
+}
+
+#[async_trait(?Send)]
+impl World for RayWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            points: HashMap::new(),
+            vectors: HashMap::new(),
+            rays: HashMap::new(),
+            transforms: HashMap::new(),
+        })
+    }
+}
+
+#[given(regex = r"^(\w+) ← (point|vector)\(([^)]*)\)$")]
+fn create_tuple(world: &mut RayWorld, name: String, tuple_type: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    match tuple_type.as_str() {
+        "point" => {
+            world.points.insert(name, point(x, y, z));
+        }
+        _ => {
+            world.vectors.insert(name, vector(x, y, z));
+        }
+    }
+}
+
+#[given(regex = r"^(\w+) ← ray\(point\(([^)]*)\), vector\(([^)]*)\)\)$")]
+fn create_ray_inline(world: &mut RayWorld, name: String, origin: String, direction: String) {
+    let (x, y, z) = parse_triple(&origin);
+    let (dx, dy, dz) = parse_triple(&direction);
+    world
+        .rays
+        .insert(name, ray(point(x, y, z), vector(dx, dy, dz)));
+}
+
+#[given(regex = r"^(\w+) ← (translation|scaling)\(([^)]*)\)$")]
+fn create_transformation(world: &mut RayWorld, name: String, kind: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    let transform = match kind.as_str() {
+        "translation" => translation(x, y, z),
+        _ => scaling(x, y, z),
+    };
+    world.transforms.insert(name, transform);
+}
+
+#[when(regex = r"^(\w+) ← ray\((\w+), (\w+)\)$")]
+fn when_ray_created(world: &mut RayWorld, name: String, origin: String, direction: String) {
+    let origin = world.points[&origin];
+    let direction = world.vectors[&direction];
+    world.rays.insert(name, ray(origin, direction));
+}
+
+#[when(regex = r"^(\w+) ← transform\((\w+), (\w+)\)$")]
+fn when_ray_transformed(world: &mut RayWorld, name: String, source: String, transform: String) {
+    let transformed = world.ray(&source).transform(world.transforms[&transform]);
+    world.rays.insert(name, transformed);
+}
+
+#[then(regex = r"^(\w+)\.origin = (\w+)$")]
+fn assert_ray_origin(world: &mut RayWorld, name: String, expected: String) {
+    assert_eq!(world.ray(&name).origin, world.points[&expected]);
+}
+
+#[then(regex = r"^(\w+)\.direction = (\w+)$")]
+fn assert_ray_direction(world: &mut RayWorld, name: String, expected: String) {
+    assert_eq!(world.ray(&name).direction, world.vectors[&expected]);
+}
+
+#[then(regex = r"^(\w+)\.(origin|direction) = (point|vector)\(([^)]*)\)$")]
+fn assert_ray_property(
+    world: &mut RayWorld,
+    name: String,
+    property: String,
+    _tuple_type: String,
+    values: String,
+) {
+    let r = world.ray(&name);
+    let (x, y, z) = parse_triple(&values);
+    match property.as_str() {
+        "origin" => assert_eq!(r.origin, point(x, y, z)),
+        _ => assert_eq!(r.direction, vector(x, y, z)),
+    }
+}
+
+#[then(regex = r"^position\((\w+), ([^)]*)\) = point\(([^)]*)\)$")]
+fn assert_ray_position(world: &mut RayWorld, name: String, t: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.ray(&name).position(parse_number(&t)), point(x, y, z));
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/rays.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        RayWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<RayWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/rays.feature"),
+    );
+}
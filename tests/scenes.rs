@@ -0,0 +1,274 @@
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::gherkin::Step;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{point, ray, vector, view_transform};
+use ray_tracer::graphics::color;
+use ray_tracer::parsers::{parse_scene, Scene, SceneError};
+use ray_tracer::shapes::Shape;
+use ray_tracer::Float;
+
+mod common;
+
+use common::{parse_number, parse_transform, parse_triple};
+
+/// camera prepended by "a scene with a camera and:" so scenarios only list what they test
+const CAMERA: &str = "\
+- add: camera
+  width: 10
+  height: 10
+  field-of-view: 1
+  from: [ 0, 0, -5 ]
+  to: [ 0, 0, 0 ]
+  up: [ 0, 1, 0 ]
+";
+
+#[derive(Debug, WorldInit)]
+struct ScenesWorld {
+    file: String,
+    scene: Option<Result<Scene, SceneError>>,
+}
+
+impl ScenesWorld {
+    fn scene(&self) -> &Scene {
+        match &self.scene {
+            Some(Ok(scene)) => scene,
+            Some(Err(error)) => panic!("Failed to parse scene: {}", error),
+            None => panic!("Scene not parsed"),
+        }
+    }
+
+    fn object(&self, index: usize) -> &dyn Shape {
+        self.scene().world.objects[index].as_ref()
+    }
+}
+
+#[async_trait(?Send)]
+impl World for ScenesWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            file: String::new(),
+            scene: Option::None,
+        })
+    }
+}
+
+fn docstring(step: &Step) -> &str {
+    let docstring = step
+        .docstring()
+        .unwrap_or_else(|| panic!("Missing docstring"));
+    docstring.strip_prefix('\n').unwrap_or(docstring)
+}
+
+#[given(regex = r"^file ← a scene containing:$")]
+fn create_file(world: &mut ScenesWorld, step: &Step) {
+    world.file = docstring(step).to_string();
+}
+
+#[given(regex = r"^file ← a scene with a camera and:$")]
+fn create_file_with_camera(world: &mut ScenesWorld, step: &Step) {
+    world.file = format!("{}{}", CAMERA, docstring(step));
+}
+
+#[when(regex = r"^scene ← parse_scene\(file\)$")]
+fn when_scene_parsed(world: &mut ScenesWorld) {
+    world.scene = Some(parse_scene(&world.file));
+}
+
+#[then(regex = r"^scene.camera.(hsize|vsize) = (\d+)$")]
+fn assert_camera_size(world: &mut ScenesWorld, property: String, size: usize) {
+    let camera = world.scene().camera;
+    let actual = match property.as_str() {
+        "hsize" => camera.hsize(),
+        _ => camera.vsize(),
+    };
+    assert_eq!(actual, size);
+}
+
+#[then(regex = r"^scene.camera.field_of_view = (.+)$")]
+fn assert_camera_field_of_view(world: &mut ScenesWorld, value: String) {
+    assert_eq!(world.scene().camera.field_of_view(), parse_number(&value));
+}
+
+#[then(
+    regex = r"^scene.camera.transform = view_transform\(point\(([^)]*)\), point\(([^)]*)\), vector\(([^)]*)\)\)$"
+)]
+fn assert_camera_transform(world: &mut ScenesWorld, from: String, to: String, up: String) {
+    let (fx, fy, fz) = parse_triple(&from);
+    let (tx, ty, tz) = parse_triple(&to);
+    let (ux, uy, uz) = parse_triple(&up);
+    assert_eq!(
+        world.scene().camera.transform(),
+        view_transform(point(fx, fy, fz), point(tx, ty, tz), vector(ux, uy, uz))
+    );
+}
+
+#[then(regex = r"^scene.world contains (\d+) (object|light)s?$")]
+fn assert_world_size(world: &mut ScenesWorld, count: usize, kind: String) {
+    let scene_world = &world.scene().world;
+    let actual = match kind.as_str() {
+        "object" => scene_world.objects.len(),
+        _ => scene_world.lights.len(),
+    };
+    assert_eq!(actual, count);
+}
+
+#[then(regex = r"^scene.world.lights\[(\d+)\].position = point\(([^)]*)\)$")]
+fn assert_light_position(world: &mut ScenesWorld, index: usize, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.scene().world.lights[index].position, point(x, y, z));
+}
+
+#[then(regex = r"^scene.world.lights\[(\d+)\].intensity = color\(([^)]*)\)$")]
+fn assert_light_intensity(world: &mut ScenesWorld, index: usize, values: String) {
+    let (red, green, blue) = parse_triple(&values);
+    assert_eq!(
+        world.scene().world.lights[index].intensity,
+        color(red, green, blue)
+    );
+}
+
+#[then(regex = r"^scene.world.objects\[(\d+)\].transform = (.+)$")]
+fn assert_object_transform(world: &mut ScenesWorld, index: usize, expression: String) {
+    assert_eq!(
+        world.object(index).transform(),
+        parse_transform(&expression)
+    );
+}
+
+#[then(regex = r"^scene.world.objects\[(\d+)\].material.color = color\(([^)]*)\)$")]
+fn assert_object_color(world: &mut ScenesWorld, index: usize, values: String) {
+    let (red, green, blue) = parse_triple(&values);
+    assert_eq!(
+        world.object(index).material().color,
+        color(red, green, blue)
+    );
+}
+
+#[then(
+    regex = r"^scene.world.objects\[(\d+)\].material.(ambient|diffuse|specular|reflective|refractive_index) = (.+)$"
+)]
+fn assert_object_material_property(
+    world: &mut ScenesWorld,
+    index: usize,
+    property: String,
+    value: String,
+) {
+    let m = world.object(index).material();
+    let actual = match property.as_str() {
+        "ambient" => m.ambient,
+        "diffuse" => m.diffuse,
+        "specular" => m.specular,
+        "reflective" => m.reflective,
+        _ => m.refractive_index,
+    };
+    assert_eq!(actual, Float::from(parse_number(&value)));
+}
+
+#[then(regex = r"^scene.world.objects\[(\d+)\].material.pattern.transform = (.+)$")]
+fn assert_pattern_transform(world: &mut ScenesWorld, index: usize, expression: String) {
+    let pattern = world
+        .object(index)
+        .material()
+        .pattern
+        .clone()
+        .unwrap_or_else(|| panic!("Material has no pattern"));
+    assert_eq!(pattern.transform(), parse_transform(&expression));
+}
+
+#[then(regex = r"^scene.world.objects\[(\d+)\].bounds.(min|max) = point\(([^)]*)\)$")]
+fn assert_object_bounds(world: &mut ScenesWorld, index: usize, corner: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    let bounds = world.object(index).bounds();
+    let actual = match corner.as_str() {
+        "min" => bounds.min,
+        _ => bounds.max,
+    };
+    assert_eq!(actual, point(x, y, z));
+}
+
+#[then(
+    regex = r"^local_intersect\(scene.world.objects\[(\d+)\], ray\(point\(([^)]*)\), vector\(([^)]*)\)\)\).count = (\d+)$"
+)]
+fn assert_object_intersections(
+    world: &mut ScenesWorld,
+    index: usize,
+    origin: String,
+    direction: String,
+    count: usize,
+) {
+    let (x, y, z) = parse_triple(&origin);
+    let (dx, dy, dz) = parse_triple(&direction);
+    let xs = world
+        .object(index)
+        .local_intersect(&ray(point(x, y, z), vector(dx, dy, dz)));
+    assert_eq!(xs.len(), count);
+}
+
+#[then(regex = r"^scene.world.objects\[(\d+)\] has (\d+) children$")]
+fn assert_children_count(world: &mut ScenesWorld, index: usize, count: usize) {
+    assert_eq!(world.object(index).children().len(), count);
+}
+
+#[then(regex = r"^scene.world.objects\[(\d+)\].children\[(\d+)\].transform = (.+)$")]
+fn assert_child_transform(world: &mut ScenesWorld, index: usize, child: usize, expression: String) {
+    assert_eq!(
+        world.object(index).children()[child].transform(),
+        parse_transform(&expression)
+    );
+}
+
+#[then(regex = r"^scene.world.objects\[(\d+)\].children\[(\d+)\] world transform = (.+)$")]
+fn assert_child_world_transform(
+    world: &mut ScenesWorld,
+    index: usize,
+    child: usize,
+    expression: String,
+) {
+    assert_eq!(
+        world.object(index).children()[child]
+            .properties()
+            .world_transform(),
+        parse_transform(&expression)
+    );
+}
+
+#[then(regex = r#"^parsing fails(?: with "(.+)")?$"#)]
+fn assert_parse_error(world: &mut ScenesWorld, message: String) {
+    match &world.scene {
+        Some(Err(error)) if message.is_empty() => {
+            assert!(matches!(error, SceneError::InvalidDocument { .. }))
+        }
+        Some(Err(error)) => assert_eq!(error.to_string(), message),
+        Some(Ok(_)) => panic!("Scene parsed without errors"),
+        None => panic!("Scene not parsed"),
+    }
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/scenes.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        ScenesWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<ScenesWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/scenes.feature"),
+    );
+}
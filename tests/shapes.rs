@@ -0,0 +1,149 @@
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{point, ray, vector, Ray, Vector};
+use ray_tracer::shapes::{sphere, Shape};
+
+mod common;
+
+use common::{parse_transform, parse_triple, test_shape, TestShape};
+
+fn is_shape<S: Shape>(_shape: &S) -> bool {
+    true
+}
+
+#[derive(Debug, WorldInit)]
+struct ShapeWorld {
+    ray: Option<Ray>,
+    shape: Option<TestShape>,
+    shapes: Vec<Box<dyn Shape>>,
+    normal: Option<Vector>,
+}
+
+impl ShapeWorld {
+    fn shape(&mut self) -> &mut TestShape {
+        self.shape
+            .as_mut()
+            .unwrap_or_else(|| panic!("Shape not created"))
+    }
+
+    fn saved_ray(&mut self) -> Ray {
+        self.shape()
+            .saved_ray
+            .lock()
+            .unwrap()
+            .unwrap_or_else(|| panic!("Shape was not intersected"))
+    }
+}
+
+#[async_trait(?Send)]
+impl World for ShapeWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            ray: Option::None,
+            shape: Option::None,
+            shapes: vec![],
+            normal: Option::None,
+        })
+    }
+}
+
+#[given(regex = r"^r ← ray\(point\(([^)]*)\), vector\(([^)]*)\)\)$")]
+fn create_ray(world: &mut ShapeWorld, origin: String, direction: String) {
+    let (x, y, z) = parse_triple(&origin);
+    let (dx, dy, dz) = parse_triple(&direction);
+    world.ray = Some(ray(point(x, y, z), vector(dx, dy, dz)));
+}
+
+#[given(regex = r"^s ← test_shape\(\)$")]
+fn create_test_shape(world: &mut ShapeWorld) {
+    world.shape = Some(test_shape());
+}
+
+#[given(regex = r"^s ← sphere\(\)$")]
+fn create_sphere(world: &mut ShapeWorld) {
+    world.shapes = vec![Box::new(sphere())];
+}
+
+#[given(regex = r"^shapes ← \[sphere\(\), test_shape\(\)\]$")]
+fn create_shape_list(world: &mut ShapeWorld) {
+    world.shapes = vec![Box::new(sphere()), Box::new(test_shape())];
+}
+
+#[when(regex = r"^set_transform\(s, (.+)\)$")]
+fn when_shape_transformed(world: &mut ShapeWorld, expression: String) {
+    world.shape().set_transform(parse_transform(&expression));
+}
+
+#[when(regex = r"^xs ← intersect\(s, r\)$")]
+fn when_ray_intersects_shape(world: &mut ShapeWorld) {
+    let r = world.ray.unwrap_or_else(|| panic!("Ray not created"));
+    world.shape().intersect(&r);
+}
+
+#[when(regex = r"^n ← normal_at\(s, point\(([^)]*)\)\)$")]
+fn when_normal_computed(world: &mut ShapeWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.normal = Some(world.shape().normal_at(point(x, y, z)));
+}
+
+#[then(regex = r"^s.transform = (.+)$")]
+fn assert_shape_transform(world: &mut ShapeWorld, expression: String) {
+    assert_eq!(world.shape().transform(), parse_transform(&expression));
+}
+
+#[then(regex = r"^s.saved_ray.origin = point\(([^)]*)\)$")]
+fn assert_saved_ray_origin(world: &mut ShapeWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.saved_ray().origin, point(x, y, z));
+}
+
+#[then(regex = r"^s.saved_ray.direction = vector\(([^)]*)\)$")]
+fn assert_saved_ray_direction(world: &mut ShapeWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.saved_ray().direction, vector(x, y, z));
+}
+
+#[then(regex = r"^n = vector\(([^)]*)\)$")]
+fn assert_normal(world: &mut ShapeWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.normal, Some(vector(x, y, z)));
+}
+
+#[then(regex = r"^s is a shape$")]
+fn assert_sphere_is_shape(_world: &mut ShapeWorld) {
+    assert!(is_shape(&sphere()));
+}
+
+#[then(regex = r"^shapes.count = (\d+)$")]
+fn assert_shape_count(world: &mut ShapeWorld, count: usize) {
+    assert_eq!(world.shapes.len(), count);
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/shapes.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        ShapeWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<ShapeWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/shapes.feature"),
+    );
+}
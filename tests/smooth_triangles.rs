@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{point, ray, vector, Point, Ray, Vector};
+use ray_tracer::scene::prepare_computations;
+use ray_tracer::shapes::{
+    intersection_with_uv, intersections, smooth_triangle, Intersection, Shape, SmoothTriangle,
+};
+use ray_tracer::Float;
+
+mod common;
+
+use common::{parse_number, parse_triple};
+
+/// intersections are kept as `(t, u, v)` so the world does not borrow from itself
+type IntersectionRecord = (Float, Float, Float);
+
+#[derive(Debug, WorldInit)]
+struct SmoothTriangleWorld {
+    points: HashMap<String, Point>,
+    vectors: HashMap<String, Vector>,
+    triangle: Option<SmoothTriangle>,
+    ray: Option<Ray>,
+    intersection: Option<IntersectionRecord>,
+    xs: Vec<IntersectionRecord>,
+    normal: Option<Vector>,
+}
+
+impl SmoothTriangleWorld {
+    fn triangle(&self) -> &SmoothTriangle {
+        self.triangle
+            .as_ref()
+            .unwrap_or_else(|| panic!("Smooth triangle not created"))
+    }
+
+    fn ray(&self) -> Ray {
+        self.ray.unwrap_or_else(|| panic!("Ray not created"))
+    }
+
+    fn intersection(&self) -> Intersection<'_> {
+        let (t, u, v) = self
+            .intersection
+            .unwrap_or_else(|| panic!("Intersection not created"));
+        intersection_with_uv(t, self.triangle(), u, v)
+    }
+}
+
+#[async_trait(?Send)]
+impl World for SmoothTriangleWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            points: HashMap::new(),
+            vectors: HashMap::new(),
+            triangle: Option::None,
+            ray: Option::None,
+            intersection: Option::None,
+            xs: vec![],
+            normal: Option::None,
+        })
+    }
+}
+
+#[given(regex = r"^(p\d) ← point\(([^)]*)\)$")]
+fn create_point(world: &mut SmoothTriangleWorld, name: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.points.insert(name, point(x, y, z));
+}
+
+#[given(regex = r"^(n\d) ← vector\(([^)]*)\)$")]
+fn create_vector(world: &mut SmoothTriangleWorld, name: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.vectors.insert(name, vector(x, y, z));
+}
+
+#[given(regex = r"^tri ← smooth_triangle\(p1, p2, p3, n1, n2, n3\)$")]
+fn create_smooth_triangle(world: &mut SmoothTriangleWorld) {
+    world.triangle = Some(smooth_triangle(
+        world.points["p1"],
+        world.points["p2"],
+        world.points["p3"],
+        world.vectors["n1"],
+        world.vectors["n2"],
+        world.vectors["n3"],
+    ));
+}
+
+#[when(regex = r"^r ← ray\(point\(([^)]*)\), vector\(([^)]*)\)\)$")]
+fn when_ray_created(world: &mut SmoothTriangleWorld, origin: String, direction: String) {
+    let (x, y, z) = parse_triple(&origin);
+    let (dx, dy, dz) = parse_triple(&direction);
+    world.ray = Some(ray(point(x, y, z), vector(dx, dy, dz)));
+}
+
+#[when(regex = r"^i ← intersection_with_uv\(([^,]+), tri, ([^,]+), ([^,]+)\)$")]
+fn when_intersection_created(world: &mut SmoothTriangleWorld, t: String, u: String, v: String) {
+    world.intersection = Some((
+        Float::from(parse_number(&t)),
+        Float::from(parse_number(&u)),
+        Float::from(parse_number(&v)),
+    ));
+}
+
+#[when(regex = r"^xs ← local_intersect\(tri, r\)$")]
+fn when_triangle_intersected(world: &mut SmoothTriangleWorld) {
+    let r = world.ray();
+    world.xs = world
+        .triangle()
+        .local_intersect(&r)
+        .iter()
+        .map(|i| (i.t, i.u, i.v))
+        .collect();
+}
+
+#[when(regex = r"^xs ← intersections\(i\)$")]
+fn when_intersections_aggregated(world: &mut SmoothTriangleWorld) {
+    world.xs = vec![world
+        .intersection
+        .unwrap_or_else(|| panic!("Intersection not created"))];
+}
+
+#[when(regex = r"^n ← normal_at\(tri, point\(([^)]*)\), i\)$")]
+fn when_normal_computed_at_hit(world: &mut SmoothTriangleWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    let normal = world
+        .triangle()
+        .normal_at_hit(point(x, y, z), &world.intersection());
+    world.normal = Some(normal);
+}
+
+#[when(regex = r"^n ← normal_at\(tri, point\(([^)]*)\)\)$")]
+fn when_normal_computed(world: &mut SmoothTriangleWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.normal = Some(world.triangle().normal_at(point(x, y, z)));
+}
+
+#[when(regex = r"^comps ← prepare_computations\(i, r, xs\)$")]
+fn when_computations_prepared(world: &mut SmoothTriangleWorld) {
+    let r = world.ray();
+    let hit = world.intersection();
+    let xs = intersections(
+        world
+            .xs
+            .iter()
+            .map(|(t, u, v)| intersection_with_uv(*t, world.triangle(), *u, *v))
+            .collect(),
+    );
+    let normal = prepare_computations(&hit, &r, &xs).normalv;
+    world.normal = Some(normal);
+}
+
+#[then(regex = r"^tri.(p1|p2|p3) = (p\d)$")]
+fn assert_triangle_corner(world: &mut SmoothTriangleWorld, corner: String, name: String) {
+    let tri = world.triangle();
+    let actual = match corner.as_str() {
+        "p1" => tri.p1(),
+        "p2" => tri.p2(),
+        _ => tri.p3(),
+    };
+    assert_eq!(actual, world.points[&name]);
+}
+
+#[then(regex = r"^tri.(n1|n2|n3) = (n\d)$")]
+fn assert_triangle_normal(world: &mut SmoothTriangleWorld, corner: String, name: String) {
+    let tri = world.triangle();
+    let actual = match corner.as_str() {
+        "n1" => tri.n1(),
+        "n2" => tri.n2(),
+        _ => tri.n3(),
+    };
+    assert_eq!(actual, world.vectors[&name]);
+}
+
+#[then(regex = r"^i.(u|v) = (.+)$")]
+fn assert_intersection_uv(world: &mut SmoothTriangleWorld, property: String, value: String) {
+    let i = world.intersection();
+    let actual = match property.as_str() {
+        "u" => i.u,
+        _ => i.v,
+    };
+    assert_eq!(actual, parse_number(&value));
+}
+
+#[then(regex = r"^xs\[(\d+)\].(u|v) = (.+)$")]
+fn assert_recorded_uv(
+    world: &mut SmoothTriangleWorld,
+    index: usize,
+    property: String,
+    value: String,
+) {
+    let (_, u, v) = world.xs[index];
+    let actual = match property.as_str() {
+        "u" => u,
+        _ => v,
+    };
+    assert_eq!(actual, parse_number(&value));
+}
+
+#[then(regex = r"^(?:n|comps.normalv) = vector\(([^)]*)\)$")]
+fn assert_normal(world: &mut SmoothTriangleWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.normal, Some(vector(x, y, z)));
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/smooth_triangles.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        SmoothTriangleWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<SmoothTriangleWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/smooth_triangles.feature"),
+    );
+}
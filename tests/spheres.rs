@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{normalize, point, ray, vector, Matrix, Ray, Vector};
+use ray_tracer::shapes::{glass_sphere, sphere, Shape, Sphere};
+use ray_tracer::Float;
+
+mod common;
+
+use common::{parse_number, parse_transform, parse_triple};
+
+#[derive(Debug, WorldInit)]
+struct SphereWorld {
+    ray: Option<Ray>,
+    sphere: Option<Sphere>,
+    transforms: HashMap<String, Matrix<4>>,
+    intersections: Vec<Float>,
+    normal: Option<Vector>,
+}
+
+impl SphereWorld {
+    fn sphere(&mut self) -> &mut Sphere {
+        self.sphere
+            .as_mut()
+            .unwrap_or_else(|| panic!("Sphere not created"))
+    }
+
+    fn transform(&self, expression: &str) -> Matrix<4> {
+        match self.transforms.get(expression) {
+            Some(transform) => *transform,
+            None => parse_transform(expression),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl World for SphereWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            ray: Option::None,
+            sphere: Option::None,
+            transforms: HashMap::new(),
+            intersections: vec![],
+            normal: Option::None,
+        })
+    }
+}
+
+#[given(regex = r"^r ← ray\(point\(([^)]*)\), vector\(([^)]*)\)\)$")]
+fn create_ray(world: &mut SphereWorld, origin: String, direction: String) {
+    let (x, y, z) = parse_triple(&origin);
+    let (dx, dy, dz) = parse_triple(&direction);
+    world.ray = Some(ray(point(x, y, z), vector(dx, dy, dz)));
+}
+
+#[given(regex = r"^s ← sphere\(\)$")]
+fn create_sphere(world: &mut SphereWorld) {
+    world.sphere = Some(sphere());
+}
+
+#[given(regex = r"^s ← glass_sphere\(\)$")]
+fn create_glass_sphere(world: &mut SphereWorld) {
+    world.sphere = Some(glass_sphere());
+}
+
+#[given(regex = r"^(\w+) ← ((?:translation|scaling|rotation_[xyz]|shearing)\(.*)$")]
+fn create_transform(world: &mut SphereWorld, name: String, expression: String) {
+    world.transforms.insert(name, parse_transform(&expression));
+}
+
+#[given(regex = r"^set_transform\(s, (.+)\)$")]
+#[when(regex = r"^set_transform\(s, (.+)\)$")]
+fn when_sphere_transformed(world: &mut SphereWorld, expression: String) {
+    let transform = world.transform(&expression);
+    world.sphere().set_transform(transform);
+}
+
+#[when(regex = r"^xs ← intersect\(s, r\)$")]
+fn when_ray_intersects_sphere(world: &mut SphereWorld) {
+    let r = world.ray.unwrap_or_else(|| panic!("Ray not created"));
+    world.intersections = world.sphere().intersect(&r).iter().map(|i| i.t).collect();
+}
+
+#[when(regex = r"^n ← normal_at\(s, point\(([^)]*)\)\)$")]
+fn when_normal_computed(world: &mut SphereWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.normal = Some(world.sphere().normal_at(point(x, y, z)));
+}
+
+#[then(regex = r"^xs.count = (\d+)$")]
+fn assert_intersection_count(world: &mut SphereWorld, count: usize) {
+    assert_eq!(world.intersections.len(), count);
+}
+
+#[then(regex = r"^xs\[(\d+)\].t = (-?\d+\.?\d*)$")]
+fn assert_intersection_distance(world: &mut SphereWorld, index: usize, expected: f64) {
+    assert_eq!(world.intersections[index], expected);
+}
+
+#[then(regex = r"^s.transform = (.+)$")]
+fn assert_sphere_transform(world: &mut SphereWorld, expression: String) {
+    let expected = world.transform(&expression);
+    assert_eq!(world.sphere().transform(), expected);
+}
+
+#[then(regex = r"^s.material.(transparency|refractive_index) = (.+)$")]
+fn assert_sphere_material(world: &mut SphereWorld, property: String, value: String) {
+    let material = world.sphere().material().clone();
+    let actual = match property.as_str() {
+        "transparency" => material.transparency,
+        _ => material.refractive_index,
+    };
+    assert_eq!(actual, parse_number(&value));
+}
+
+#[then(regex = r"^n = vector\(([^)]*)\)$")]
+fn assert_normal(world: &mut SphereWorld, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    assert_eq!(world.normal, Some(vector(x, y, z)));
+}
+
+#[then(regex = r"^n = normalize\(n\)$")]
+fn assert_normal_is_normalized(world: &mut SphereWorld) {
+    let n = world
+        .normal
+        .unwrap_or_else(|| panic!("Normal not computed"));
+    assert_eq!(n, normalize(n));
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/spheres.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        SphereWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<SphereWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/spheres.feature"),
+    );
+}
@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::gherkin::Step;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{
+    identity_matrix, point, rotation_x, rotation_y, rotation_z, scaling, shearing, translation,
+    vector, view_transform, Matrix, Point, Vector,
+};
+
+mod common;
+
+use common::{parse_number, parse_numbers, parse_transform, parse_triple};
+
+#[derive(Clone, Copy, Debug)]
+enum TupleType {
+    PointTuple(Point),
+    VectorTuple(Vector),
+}
+
+fn build_transformation(name: &str, arguments: &[f64]) -> Matrix<4> {
+    match (name, arguments) {
+        ("translation", [x, y, z]) => translation(*x, *y, *z),
+        ("scaling", [x, y, z]) => scaling(*x, *y, *z),
+        ("rotation_x", [radians]) => rotation_x(*radians),
+        ("rotation_y", [radians]) => rotation_y(*radians),
+        ("rotation_z", [radians]) => rotation_z(*radians),
+        ("shearing", [x_y, x_z, y_x, y_z, z_x, z_y]) => {
+            shearing(*x_y, *x_z, *y_x, *y_z, *z_x, *z_y)
+        }
+        _ => panic!("Unknown transformation: {}{:?}", name, arguments),
+    }
+}
+
+fn apply_fluent_transformation(transform: Matrix<4>, name: &str, arguments: &[f64]) -> Matrix<4> {
+    match (name, arguments) {
+        ("translate", [x, y, z]) => transform.translate(*x, *y, *z),
+        ("scale", [x, y, z]) => transform.scale(*x, *y, *z),
+        ("rotate_x", [radians]) => transform.rotate_x(*radians),
+        ("rotate_y", [radians]) => transform.rotate_y(*radians),
+        ("rotate_z", [radians]) => transform.rotate_z(*radians),
+        ("shear", [x_y, x_z, y_x, y_z, z_x, z_y]) => {
+            transform.shear(*x_y, *x_z, *y_x, *y_z, *z_x, *z_y)
+        }
+        _ => panic!("Unknown fluent transformation: {}{:?}", name, arguments),
+    }
+}
+
+#[derive(Debug, WorldInit)]
+struct TransformationWorld {
+    transforms: HashMap<String, Matrix<4>>,
+    tuples: HashMap<String, TupleType>,
+}
+
+impl TransformationWorld {
+    fn transform(&self, name: &str) -> Matrix<4> {
+        *self
+            .transforms
+            .get(name)
+            .unwrap_or_else(|| panic!("Transformation {} not created", name))
+    }
+
+    fn tuple(&self, name: &str) -> TupleType {
+        *self
+            .tuples
+            .get(name)
+            .unwrap_or_else(|| panic!("Tuple {} not created", name))
+    }
+
+    fn point(&self, name: &str) -> Point {
+        match self.tuple(name) {
+            TupleType::PointTuple(p) => p,
+            result => panic!("Expected point but got {:?}", result),
+        }
+    }
+
+    fn apply(&self, transform: &str, tuple: &str) -> TupleType {
+        let transform = self.transform(transform);
+        match self.tuple(tuple) {
+            TupleType::PointTuple(p) => TupleType::PointTuple(transform * p),
+            TupleType::VectorTuple(v) => TupleType::VectorTuple(transform * v),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl World for TransformationWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            transforms: HashMap::new(),
+            tuples: HashMap::new(),
+        })
+    }
+}
+
+#[given(regex = r"^(\w+) ← (point|vector)\(([^)]*)\)$")]
+fn create_tuple(world: &mut TransformationWorld, name: String, tuple_type: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    let tuple = match tuple_type.as_str() {
+        "point" => TupleType::PointTuple(point(x, y, z)),
+        "vector" => TupleType::VectorTuple(vector(x, y, z)),
+        _ => panic!("Unexpected tuple type: {}", tuple_type),
+    };
+    world.tuples.insert(name, tuple);
+}
+
+#[given(regex = r"^(\w+) ← (translation|scaling|rotation_[xyz]|shearing)\(([^)]*)\)$")]
+fn create_transformation(
+    world: &mut TransformationWorld,
+    name: String,
+    transformation: String,
+    arguments: String,
+) {
+    let transform = build_transformation(&transformation, &parse_numbers(&arguments));
+    world.transforms.insert(name, transform);
+}
+
+#[given(regex = r"^(\w+) ← inverse\((\w+)\)$")]
+fn create_inverse(world: &mut TransformationWorld, name: String, source: String) {
+    let inverse = world
+        .transform(&source)
+        .inverse()
+        .unwrap_or_else(|error| panic!("Failed to invert {}: {}", source, error));
+    world.transforms.insert(name, inverse);
+}
+
+#[when(regex = r"^(\w+) ← (\w+) \* (\w+)$")]
+fn when_transform_applied(
+    world: &mut TransformationWorld,
+    name: String,
+    transform: String,
+    tuple: String,
+) {
+    let result = world.apply(&transform, &tuple);
+    world.tuples.insert(name, result);
+}
+
+#[when(regex = r"^(\w+) ← (\w+(?: \* \w+){2,})$")]
+fn when_transforms_chained(world: &mut TransformationWorld, name: String, chain: String) {
+    let transform = chain
+        .split(" * ")
+        .map(|operand| world.transform(operand))
+        .reduce(|product, transform| product * transform)
+        .unwrap();
+    world.transforms.insert(name, transform);
+}
+
+#[when(regex = r"^(\w+) ← identity_matrix((?:\.\w+\([^)]*\))+)$")]
+fn when_fluent_transforms_chained(world: &mut TransformationWorld, name: String, chain: String) {
+    let transform =
+        chain
+            .trim_start_matches('.')
+            .split(").")
+            .fold(identity_matrix(), |transform, call| {
+                let (method, arguments) = call
+                    .trim_end_matches(')')
+                    .split_once('(')
+                    .unwrap_or_else(|| panic!("Invalid method call: {}", call));
+                apply_fluent_transformation(transform, method, &parse_numbers(arguments))
+            });
+    world.transforms.insert(name, transform);
+}
+
+#[when(regex = r"^(\w+) ← view_transform\((\w+), (\w+), (\w+)\)$")]
+fn when_view_transform_created(
+    world: &mut TransformationWorld,
+    name: String,
+    from: String,
+    to: String,
+    up: String,
+) {
+    let up = match world.tuple(&up) {
+        TupleType::VectorTuple(v) => v,
+        result => panic!("Expected vector but got {:?}", result),
+    };
+    let transform = view_transform(world.point(&from), world.point(&to), up);
+    world.transforms.insert(name, transform);
+}
+
+#[then(regex = r"^(\w+) \* (\w+) = (point|vector)\(([^)]*)\)$")]
+fn assert_transformed_tuple(
+    world: &mut TransformationWorld,
+    transform: String,
+    tuple: String,
+    tuple_type: String,
+    values: String,
+) {
+    let (x, y, z) = parse_triple(&values);
+    match (world.apply(&transform, &tuple), tuple_type.as_str()) {
+        (TupleType::PointTuple(p), "point") => assert_eq!(p, point(x, y, z)),
+        (TupleType::VectorTuple(v), "vector") => assert_eq!(v, vector(x, y, z)),
+        (result, _) => panic!("Expected {} but got {:?}", tuple_type, result),
+    }
+}
+
+#[then(regex = r"^(\w+) \* (\w+) = (\w+)$")]
+fn assert_transformed_tuple_unchanged(
+    world: &mut TransformationWorld,
+    transform: String,
+    tuple: String,
+    expected: String,
+) {
+    match (world.apply(&transform, &tuple), world.tuple(&expected)) {
+        (TupleType::PointTuple(p), TupleType::PointTuple(e)) => assert_eq!(p, e),
+        (TupleType::VectorTuple(v), TupleType::VectorTuple(e)) => assert_eq!(v, e),
+        (result, expected) => panic!("Expected {:?} but got {:?}", expected, result),
+    }
+}
+
+#[then(regex = r"^(\w+) = ((?:identity_matrix|translation|scaling|rotation_[xyz]|shearing).*)$")]
+fn assert_transform(world: &mut TransformationWorld, name: String, expression: String) {
+    assert_eq!(world.transform(&name), parse_transform(&expression));
+}
+
+#[then(regex = r"^(\w+) is the following 4x4 matrix:$")]
+fn assert_transform_table(world: &mut TransformationWorld, name: String, step: &Step) {
+    let table = step
+        .table
+        .as_ref()
+        .unwrap_or_else(|| panic!("Missing matrix table"));
+    let transform = world.transform(&name);
+    for (row, values) in table.rows.iter().enumerate() {
+        for (column, value) in values.iter().enumerate() {
+            assert_eq!(transform[(row, column)], parse_number(value.trim()));
+        }
+    }
+}
+
+#[then(regex = r"^(\w+) = point\(([^)]*)\)$")]
+fn assert_point(world: &mut TransformationWorld, name: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    match world.tuple(&name) {
+        TupleType::PointTuple(p) => assert_eq!(p, point(x, y, z)),
+        result => panic!("Expected point but got {:?}", result),
+    }
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/transformations.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        TransformationWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<TransformationWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/transformations.feature"),
+    );
+}
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{point, ray, vector, BoundingBox, Point, Ray, Vector};
+use ray_tracer::shapes::{triangle, Shape, Triangle};
+use ray_tracer::Float;
+
+mod common;
+
+use common::{parse_number, parse_triple};
+
+#[derive(Debug, WorldInit)]
+struct TriangleWorld {
+    points: HashMap<String, Point>,
+    normals: HashMap<String, Vector>,
+    triangle: Option<Triangle>,
+    ray: Option<Ray>,
+    intersections: Vec<Float>,
+    bounds: Option<BoundingBox>,
+}
+
+impl TriangleWorld {
+    fn triangle(&self) -> &Triangle {
+        self.triangle
+            .as_ref()
+            .unwrap_or_else(|| panic!("Triangle not created"))
+    }
+}
+
+#[async_trait(?Send)]
+impl World for TriangleWorld {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            points: HashMap::new(),
+            normals: HashMap::new(),
+            triangle: Option::None,
+            ray: Option::None,
+            intersections: vec![],
+            bounds: Option::None,
+        })
+    }
+}
+
+#[given(regex = r"^(p\d) ← point\(([^)]*)\)$")]
+fn create_point(world: &mut TriangleWorld, name: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.points.insert(name, point(x, y, z));
+}
+
+#[given(regex = r"^t ← triangle\((p\d), (p\d), (p\d)\)$")]
+fn create_triangle_from_points(world: &mut TriangleWorld, p1: String, p2: String, p3: String) {
+    world.triangle = Some(triangle(
+        world.points[&p1],
+        world.points[&p2],
+        world.points[&p3],
+    ));
+}
+
+#[given(regex = r"^t ← triangle\(point\(([^)]*)\), point\(([^)]*)\), point\(([^)]*)\)\)$")]
+fn create_triangle(world: &mut TriangleWorld, p1: String, p2: String, p3: String) {
+    let corner = |values: &str| {
+        let (x, y, z) = parse_triple(values);
+        point(x, y, z)
+    };
+    world.triangle = Some(triangle(corner(&p1), corner(&p2), corner(&p3)));
+}
+
+#[given(regex = r"^r ← ray\(point\(([^)]*)\), vector\(([^)]*)\)\)$")]
+fn create_ray(world: &mut TriangleWorld, origin: String, direction: String) {
+    let (x, y, z) = parse_triple(&origin);
+    let (dx, dy, dz) = parse_triple(&direction);
+    world.ray = Some(ray(point(x, y, z), vector(dx, dy, dz)));
+}
+
+#[when(regex = r"^(n\d) ← local_normal_at\(t, point\(([^)]*)\)\)$")]
+fn when_normal_computed(world: &mut TriangleWorld, name: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    let normal = world.triangle().local_normal_at(point(x, y, z));
+    world.normals.insert(name, normal);
+}
+
+#[when(regex = r"^xs ← local_intersect\(t, r\)$")]
+fn when_triangle_intersected(world: &mut TriangleWorld) {
+    let r = world.ray.unwrap_or_else(|| panic!("Ray not created"));
+    world.intersections = world
+        .triangle()
+        .local_intersect(&r)
+        .iter()
+        .map(|i| i.t)
+        .collect();
+}
+
+#[when(regex = r"^box ← bounds_of\(t\)$")]
+fn when_bounds_queried(world: &mut TriangleWorld) {
+    world.bounds = Some(world.triangle().bounds());
+}
+
+#[then(regex = r"^t.(p1|p2|p3) = (p\d)$")]
+fn assert_triangle_corner(world: &mut TriangleWorld, corner: String, name: String) {
+    let t = world.triangle();
+    let actual = match corner.as_str() {
+        "p1" => t.p1(),
+        "p2" => t.p2(),
+        _ => t.p3(),
+    };
+    assert_eq!(actual, world.points[&name]);
+}
+
+#[then(regex = r"^t.(e1|e2|normal) = vector\(([^)]*)\)$")]
+fn assert_triangle_vector(world: &mut TriangleWorld, property: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    let t = world.triangle();
+    let actual = match property.as_str() {
+        "e1" => t.e1(),
+        "e2" => t.e2(),
+        _ => t.normal(),
+    };
+    assert_eq!(actual, vector(x, y, z));
+}
+
+#[then(regex = r"^(n\d) = t.normal$")]
+fn assert_normal_is_triangle_normal(world: &mut TriangleWorld, name: String) {
+    assert_eq!(world.normals[&name], world.triangle().normal());
+}
+
+#[then(regex = r"^xs is empty$")]
+fn assert_no_intersections(world: &mut TriangleWorld) {
+    assert!(world.intersections.is_empty());
+}
+
+#[then(regex = r"^xs.count = (\d+)$")]
+fn assert_intersection_count(world: &mut TriangleWorld, count: usize) {
+    assert_eq!(world.intersections.len(), count);
+}
+
+#[then(regex = r"^xs\[(\d+)\].t = (.+)$")]
+fn assert_intersection_distance(world: &mut TriangleWorld, index: usize, t: String) {
+    assert_eq!(world.intersections[index], parse_number(&t));
+}
+
+#[then(regex = r"^box.(min|max) = point\(([^)]*)\)$")]
+fn assert_bounds(world: &mut TriangleWorld, corner: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    let bounds = world.bounds.unwrap_or_else(|| panic!("Bounds not queried"));
+    let actual = match corner.as_str() {
+        "min" => bounds.min,
+        _ => bounds.max,
+    };
+    assert_eq!(actual, point(x, y, z));
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/triangles.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        TriangleWorld::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<TriangleWorld, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/triangles.feature"),
+    );
+}
@@ -4,7 +4,7 @@ use async_trait::async_trait;
 use cucumber::{given, then, when, World, WorldInit};
 
 use ray_tracer::geometry::{
-    cross_product, dot_product, magnitude, normalize, point, vector, Point, Vector,
+    cross_product, dot_product, magnitude, normalize, point, reflect, vector, Point, Vector,
 };
 use ray_tracer::graphics::{color, Color};
 use ray_tracer::Float;
@@ -50,6 +50,7 @@ struct TupleWorld {
     input2: Option<TupleType>,
     color1: Option<Color>,
     color2: Option<Color>,
+    result: Option<Vector>,
 }
 
 #[async_trait(?Send)]
@@ -62,6 +63,7 @@ impl World for TupleWorld {
             input2: Option::None,
             color1: Option::None,
             color2: Option::None,
+            result: Option::None,
         })
     }
 }
@@ -396,6 +398,36 @@ fn assert_vector_cross_products(
     }
 }
 
+#[when(regex = r"^r ← reflect\(v, n\)$")]
+fn when_vector_reflected(world: &mut TupleWorld) {
+    let unwrap_vector =
+        |tuple: Option<TupleType>| match tuple.unwrap_or_else(|| panic!("No tuple available")) {
+            TupleType::VectorTuple(v) => v,
+            _ => panic!("Only vectors can be reflected"),
+        };
+
+    world.result = Some(reflect(
+        unwrap_vector(world.input1),
+        unwrap_vector(world.input2),
+    ));
+}
+
+#[then(regex = r"^r = vector\((-?\d+.?\d*), (-?\d+.?\d*), (-?\d+.?\d*)\)$")]
+fn assert_reflected_vector(
+    world: &mut TupleWorld,
+    expected_x: f64,
+    expected_y: f64,
+    expected_z: f64,
+) {
+    let result = world
+        .result
+        .unwrap_or_else(|| panic!("Vector was not reflected"));
+
+    assert_eq!(result.x, expected_x);
+    assert_eq!(result.y, expected_y);
+    assert_eq!(result.z, expected_z);
+}
+
 #[given(regex = r"c\d* ← color\((-?\d+.?\d*), (-?\d+.?\d*), (-?\d+.?\d*)\)")]
 fn create_color_from_tuple(world: &mut TupleWorld, red: f64, green: f64, blue: f64) {
     let color = color(red, green, blue);
@@ -0,0 +1,467 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use async_trait::async_trait;
+use cucumber::gherkin::Step;
+use cucumber::{given, then, when, World, WorldInit};
+
+use ray_tracer::geometry::{point, ray, vector, Point, Ray};
+use ray_tracer::graphics::{color, point_light, Color, PointLight};
+use ray_tracer::scene::{
+    self, color_at, default_world, intersect_world, is_shadowed, prepare_computations,
+    reflected_color, refracted_color, schlick, shade_hit, Computations,
+};
+use ray_tracer::shapes::{glass_sphere, intersection, plane, sphere, Intersections, Shape};
+use ray_tracer::{Float, EPSILON};
+
+mod common;
+
+use common::{apply_shape_attributes, parse_number, parse_triple};
+
+/// objects are either standalone or owned by the scene
+#[derive(Clone, Debug)]
+enum ObjectRef {
+    Standalone(String),
+    InWorld(usize),
+}
+
+fn new_shape(kind: &str) -> Box<dyn Shape> {
+    match kind {
+        "sphere" => Box::new(sphere()),
+        "glass_sphere" => Box::new(glass_sphere()),
+        _ => Box::new(plane()),
+    }
+}
+
+#[derive(Debug, WorldInit)]
+struct WorldSteps {
+    world: Option<scene::World>,
+    shapes: HashMap<String, Box<dyn Shape>>,
+    names: HashMap<String, ObjectRef>,
+    point: Option<Point>,
+    ray: Option<Ray>,
+    light: Option<PointLight>,
+    intersection: Option<(Float, String)>,
+    /// intersections built by hand, stored by object name since they borrow the objects
+    records: Vec<(Float, String)>,
+    xs: Vec<Float>,
+    color: Option<Color>,
+    reflectance: Option<Float>,
+}
+
+impl WorldSteps {
+    fn world(&self) -> &scene::World {
+        self.world
+            .as_ref()
+            .unwrap_or_else(|| panic!("World not created"))
+    }
+
+    fn world_mut(&mut self) -> &mut scene::World {
+        self.world
+            .as_mut()
+            .unwrap_or_else(|| panic!("World not created"))
+    }
+
+    fn ray(&self) -> Ray {
+        self.ray.unwrap_or_else(|| panic!("Ray not created"))
+    }
+
+    fn object(&self, name: &str) -> &dyn Shape {
+        match self.names.get(name) {
+            Some(ObjectRef::InWorld(index)) => self.world().objects[*index].as_ref(),
+            Some(ObjectRef::Standalone(name)) => self.shapes[name].as_ref(),
+            None => panic!("Object {} not created", name),
+        }
+    }
+
+    fn object_mut(&mut self, name: &str) -> &mut dyn Shape {
+        match self.names.get(name).cloned() {
+            Some(ObjectRef::InWorld(index)) => self.world_mut().objects[index].as_mut(),
+            Some(ObjectRef::Standalone(name)) => self.shapes.get_mut(&name).unwrap().as_mut(),
+            None => panic!("Object {} not created", name),
+        }
+    }
+
+    fn comps(&self) -> Computations<'_> {
+        let (t, name) = self
+            .intersection
+            .as_ref()
+            .unwrap_or_else(|| panic!("Intersection not created"));
+        let hit = intersection(*t, self.object(name));
+        let xs: Intersections<'_> = if self.records.is_empty() {
+            vec![hit].into_iter().collect()
+        } else {
+            self.records
+                .iter()
+                .map(|(t, name)| intersection(*t, self.object(name)))
+                .collect()
+        };
+        prepare_computations(&hit, &self.ray(), &xs)
+    }
+}
+
+#[async_trait(?Send)]
+impl World for WorldSteps {
+    type Error = Infallible;
+
+    async fn new() -> Result<Self, Infallible> {
+        Ok(Self {
+            world: Option::None,
+            shapes: HashMap::new(),
+            names: HashMap::new(),
+            point: Option::None,
+            ray: Option::None,
+            light: Option::None,
+            intersection: Option::None,
+            records: vec![],
+            xs: vec![],
+            color: Option::None,
+            reflectance: Option::None,
+        })
+    }
+}
+
+#[given(regex = r"^w ← world\(\)$")]
+fn create_empty_world(world: &mut WorldSteps) {
+    world.world = Some(scene::world());
+}
+
+#[given(regex = r"^w ← default_world\(\)$")]
+#[when(regex = r"^w ← default_world\(\)$")]
+fn create_default_world(world: &mut WorldSteps) {
+    world.world = Some(default_world());
+}
+
+#[given(regex = r"^light ← point_light\(point\(([^)]*)\), color\(([^)]*)\)\)$")]
+fn create_light(world: &mut WorldSteps, position: String, intensity: String) {
+    let (x, y, z) = parse_triple(&position);
+    let (red, green, blue) = parse_triple(&intensity);
+    world.light = Some(point_light(point(x, y, z), color(red, green, blue)));
+}
+
+#[given(regex = r"^w.light ← point_light\(point\(([^)]*)\), color\(([^)]*)\)\)$")]
+fn replace_world_light(world: &mut WorldSteps, position: String, intensity: String) {
+    let (x, y, z) = parse_triple(&position);
+    let (red, green, blue) = parse_triple(&intensity);
+    world.world_mut().lights = vec![point_light(point(x, y, z), color(red, green, blue))];
+}
+
+#[given(regex = r"^w also has light ← point_light\(point\(([^)]*)\), color\(([^)]*)\)\)$")]
+fn add_world_light(world: &mut WorldSteps, position: String, intensity: String) {
+    let (x, y, z) = parse_triple(&position);
+    let (red, green, blue) = parse_triple(&intensity);
+    world
+        .world_mut()
+        .lights
+        .push(point_light(point(x, y, z), color(red, green, blue)));
+}
+
+#[given(regex = r"^(\w+) ← (sphere|glass_sphere|plane)\(\)$")]
+fn create_shape(world: &mut WorldSteps, name: String, kind: String) {
+    world.shapes.insert(name.clone(), new_shape(&kind));
+    world
+        .names
+        .insert(name.clone(), ObjectRef::Standalone(name));
+}
+
+#[given(regex = r"^(\w+) ← (sphere|glass_sphere|plane)\(\) with:$")]
+fn create_shape_with_attributes(world: &mut WorldSteps, name: String, kind: String, step: &Step) {
+    let mut shape = new_shape(&kind);
+    apply_shape_attributes(shape.as_mut(), step);
+    world.shapes.insert(name.clone(), shape);
+    world
+        .names
+        .insert(name.clone(), ObjectRef::Standalone(name));
+}
+
+#[given(regex = r"^w.max_depth ← (\d+)$")]
+fn set_max_depth(world: &mut WorldSteps, depth: usize) {
+    world.world_mut().max_depth = depth;
+}
+
+#[given(regex = r"^(\w+) is added to w$")]
+fn add_object_to_world(world: &mut WorldSteps, name: String) {
+    let shape = world
+        .shapes
+        .remove(&name)
+        .unwrap_or_else(|| panic!("Object {} not created", name));
+    let objects = &mut world.world_mut().objects;
+    objects.push(shape);
+    let index = objects.len() - 1;
+    world.names.insert(name, ObjectRef::InWorld(index));
+}
+
+#[given(regex = r"^(\w+) ← the (first|second) object in w$")]
+fn reference_world_object(world: &mut WorldSteps, name: String, ordinal: String) {
+    let index = match ordinal.as_str() {
+        "first" => 0,
+        _ => 1,
+    };
+    world.names.insert(name, ObjectRef::InWorld(index));
+}
+
+#[given(regex = r"^(\w+).material.ambient ← (.+)$")]
+fn set_object_ambient(world: &mut WorldSteps, name: String, value: String) {
+    let object = world.object_mut(&name);
+    let mut material = object.material().clone();
+    material.ambient = Float::from(parse_number(&value));
+    object.set_material(material);
+}
+
+#[given(regex = r"^p ← point\(([^)]*)\)$")]
+fn create_point(world: &mut WorldSteps, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    world.point = Some(point(x, y, z));
+}
+
+#[given(regex = r"^r ← ray\(point\(([^)]*)\), vector\(([^)]*)\)\)$")]
+fn create_ray(world: &mut WorldSteps, origin: String, direction: String) {
+    let (x, y, z) = parse_triple(&origin);
+    let (dx, dy, dz) = parse_triple(&direction);
+    world.ray = Some(ray(point(x, y, z), vector(dx, dy, dz)));
+}
+
+#[given(regex = r"^i ← intersection\(([^,]+), (\w+)\)$")]
+fn create_intersection(world: &mut WorldSteps, t: String, object: String) {
+    world.intersection = Some((Float::from(parse_number(&t)), object));
+}
+
+#[given(regex = r"^(\w+) has:$")]
+fn set_object_attributes(world: &mut WorldSteps, name: String, step: &Step) {
+    apply_shape_attributes(world.object_mut(&name), step);
+}
+
+#[given(regex = r"^xs ← intersections\((.+)\)$")]
+fn create_intersections(world: &mut WorldSteps, list: String) {
+    world.records = list
+        .split(", ")
+        .map(|record| match record.split_once(':') {
+            Some((t, name)) => (Float::from(parse_number(t)), name.to_string()),
+            None => world
+                .intersection
+                .clone()
+                .unwrap_or_else(|| panic!("Intersection {} not created", record)),
+        })
+        .collect();
+}
+
+#[when(regex = r"^xs ← intersect_world\(w, r\)$")]
+fn when_world_intersected(world: &mut WorldSteps) {
+    world.xs = intersect_world(world.world(), &world.ray())
+        .iter()
+        .map(|i| i.t)
+        .collect();
+}
+
+#[when(regex = r"^comps ← prepare_computations\((?:i|xs\[(\d+)\]), r(?:, xs)?\)$")]
+fn when_computations_prepared(world: &mut WorldSteps, index: String) {
+    if let Ok(index) = index.parse::<usize>() {
+        world.intersection = Some(world.records[index].clone());
+    }
+    // computations borrow the object so they are rebuilt by each assertion
+    world.comps();
+}
+
+#[when(regex = r"^c ← shade_hit\(w, comps\)$")]
+fn when_hit_shaded(world: &mut WorldSteps) {
+    let scene = world.world();
+    world.color = Some(shade_hit(scene, &world.comps(), scene.max_depth));
+}
+
+#[when(regex = r"^c ← color_at\(w, r\)$")]
+fn when_color_computed(world: &mut WorldSteps) {
+    let scene = world.world();
+    world.color = Some(color_at(scene, &world.ray(), scene.max_depth));
+}
+
+#[when(regex = r"^c ← refracted_color\(w, comps, (\d+)\)$")]
+fn when_refracted_color_computed(world: &mut WorldSteps, remaining: usize) {
+    world.color = Some(refracted_color(world.world(), &world.comps(), remaining));
+}
+
+#[when(regex = r"^c ← shade_hit\(w, comps, (\d+)\)$")]
+fn when_hit_shaded_with_depth(world: &mut WorldSteps, remaining: usize) {
+    world.color = Some(shade_hit(world.world(), &world.comps(), remaining));
+}
+
+#[when(regex = r"^reflectance ← schlick\(comps\)$")]
+fn when_reflectance_computed(world: &mut WorldSteps) {
+    world.reflectance = Some(schlick(&world.comps()));
+}
+
+#[when(regex = r"^c ← reflected_color\(w, comps(?:, (\d+))?\)$")]
+fn when_reflected_color_computed(world: &mut WorldSteps, remaining: String) {
+    let scene = world.world();
+    let remaining = match remaining.as_str() {
+        "" => scene.max_depth,
+        value => value.parse().unwrap(),
+    };
+    world.color = Some(reflected_color(scene, &world.comps(), remaining));
+}
+
+#[then(regex = r"^w contains no objects$")]
+fn assert_world_has_no_objects(world: &mut WorldSteps) {
+    assert!(world.world().objects.is_empty());
+}
+
+#[then(regex = r"^w has no light source$")]
+fn assert_world_has_no_lights(world: &mut WorldSteps) {
+    assert!(world.world().lights.is_empty());
+}
+
+#[then(regex = r"^w.light = light$")]
+fn assert_world_light(world: &mut WorldSteps) {
+    assert_eq!(world.world().lights, vec![world.light.unwrap()]);
+}
+
+#[then(regex = r"^w.max_depth = (\d+)$")]
+fn assert_max_depth(world: &mut WorldSteps, depth: usize) {
+    assert_eq!(world.world().max_depth, depth);
+}
+
+#[then(regex = r"^w.rays_cast = (\d+)$")]
+fn assert_rays_cast(world: &mut WorldSteps, count: usize) {
+    assert_eq!(world.world().rays_cast(), count);
+}
+
+#[then(regex = r"^color_at\(w, r\) should terminate successfully$")]
+fn assert_color_at_terminates(world: &mut WorldSteps) {
+    let scene = world.world();
+    color_at(scene, &world.ray(), scene.max_depth);
+}
+
+#[then(regex = r"^w contains (\w+)$")]
+fn assert_world_contains(world: &mut WorldSteps, name: String) {
+    let expected = world.object(&name);
+    assert!(world.world().objects.iter().any(|object| {
+        object.transform() == expected.transform() && object.material() == expected.material()
+    }));
+}
+
+#[then(regex = r"^xs.count = (\d+)$")]
+fn assert_intersection_count(world: &mut WorldSteps, count: usize) {
+    assert_eq!(world.xs.len(), count);
+}
+
+#[then(regex = r"^xs\[(\d+)\].t = (.+)$")]
+fn assert_intersection_distance(world: &mut WorldSteps, index: usize, t: String) {
+    assert_eq!(world.xs[index], parse_number(&t));
+}
+
+#[then(regex = r"^comps.t = i.t$")]
+fn assert_comps_distance(world: &mut WorldSteps) {
+    assert_eq!(world.comps().t, world.intersection.as_ref().unwrap().0);
+}
+
+#[then(regex = r"^comps.object = i.object$")]
+fn assert_comps_object(world: &mut WorldSteps) {
+    let name = world.intersection.as_ref().unwrap().1.clone();
+    assert!(std::ptr::addr_eq(world.comps().object, world.object(&name)));
+}
+
+#[then(regex = r"^comps.(point|over_point) = point\(([^)]*)\)$")]
+fn assert_comps_point(world: &mut WorldSteps, property: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    let comps = world.comps();
+    let actual = match property.as_str() {
+        "point" => comps.point,
+        _ => comps.over_point,
+    };
+    assert_eq!(actual, point(x, y, z));
+}
+
+#[then(regex = r"^comps.(eyev|normalv|reflectv) = vector\(([^)]*)\)$")]
+fn assert_comps_vector(world: &mut WorldSteps, property: String, values: String) {
+    let (x, y, z) = parse_triple(&values);
+    let comps = world.comps();
+    let actual = match property.as_str() {
+        "eyev" => comps.eyev,
+        "normalv" => comps.normalv,
+        _ => comps.reflectv,
+    };
+    assert_eq!(actual, vector(x, y, z));
+}
+
+#[then(regex = r"^comps.inside = (true|false)$")]
+fn assert_comps_inside(world: &mut WorldSteps, inside: bool) {
+    assert_eq!(world.comps().inside, inside);
+}
+
+#[then(regex = r"^comps.over_point.z < -EPSILON/2$")]
+fn assert_over_point_offset(world: &mut WorldSteps) {
+    assert!(world.comps().over_point.z < -EPSILON / 2.0);
+}
+
+#[then(regex = r"^comps.under_point.z > EPSILON/2$")]
+fn assert_under_point_offset(world: &mut WorldSteps) {
+    assert!(world.comps().under_point.z > EPSILON / 2.0);
+}
+
+#[then(regex = r"^comps.point.z < comps.under_point.z$")]
+fn assert_under_point_below_surface(world: &mut WorldSteps) {
+    let comps = world.comps();
+    assert!(comps.point.z < comps.under_point.z);
+}
+
+#[then(regex = r"^comps.(n1|n2) = (.+)$")]
+fn assert_refractive_index(world: &mut WorldSteps, property: String, value: String) {
+    let comps = world.comps();
+    let actual = match property.as_str() {
+        "n1" => comps.n1,
+        _ => comps.n2,
+    };
+    assert_eq!(actual, parse_number(&value));
+}
+
+#[then(regex = r"^reflectance = (.+)$")]
+fn assert_reflectance(world: &mut WorldSteps, value: String) {
+    assert_eq!(world.reflectance, Some(Float::from(parse_number(&value))));
+}
+
+#[then(regex = r"^comps.point.z > comps.over_point.z$")]
+fn assert_over_point_above_surface(world: &mut WorldSteps) {
+    let comps = world.comps();
+    assert!(comps.point.z > comps.over_point.z);
+}
+
+#[then(regex = r"^is_shadowed\(w, p\) is (true|false)$")]
+fn assert_shadowed(world: &mut WorldSteps, shadowed: bool) {
+    let scene = world.world();
+    let p = world.point.unwrap_or_else(|| panic!("Point not created"));
+    assert_eq!(is_shadowed(scene, p, &scene.lights[0]), shadowed);
+}
+
+#[then(regex = r"^c = color\(([^)]*)\)$")]
+fn assert_color(world: &mut WorldSteps, values: String) {
+    let (red, green, blue) = parse_triple(&values);
+    assert_eq!(world.color, Some(color(red, green, blue)));
+}
+
+#[then(regex = r"^c = (\w+).material.color$")]
+fn assert_color_matches_material(world: &mut WorldSteps, name: String) {
+    assert_eq!(world.color, Some(world.object(&name).material().color));
+}
+
+fn main() {
+    use cucumber::{writer, WriterExt as _};
+    use std::fs;
+
+    fs::create_dir(dbg!(format!("{}/reports", env!("CARGO_MANIFEST_DIR")))).unwrap_or(());
+
+    let file = fs::File::create(dbg!(format!(
+        "{}/reports/world.xml",
+        env!("CARGO_MANIFEST_DIR")
+    )))
+    .unwrap();
+
+    futures::executor::block_on(
+        WorldSteps::cucumber()
+            .with_writer(
+                writer::Basic::stdout()
+                    .summarized()
+                    .tee::<WorldSteps, _>(writer::JUnit::for_tee(file, 0))
+                    .normalized(),
+            )
+            .run("tests/features/world.feature"),
+    );
+}